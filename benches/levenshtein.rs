@@ -0,0 +1,175 @@
+//! Simple timing benchmarks that only rely on the standard library.
+//!
+//! Run with `cargo bench`, each benchmark prints the average time taken and the number of
+//! allocations made per iteration.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use fuzzy_string_distance::{
+    levenshtein_distance, levenshtein_distance_ascii, levenshtein_distance_ignore_ascii_case,
+    levenshtein_distance_ignore_case, levenshtein_distance_myers, levenshtein_distance_slice,
+    Levenshtein, Query,
+};
+
+/// The system allocator, but counting every allocation so each benchmark can report how many
+/// allocations it makes.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn bench<F: FnMut()>(name: &str, iterations: u32, mut f: F) {
+    // Warm up once so the first iteration doesn't pay for any lazy setup
+    f();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    let average = start.elapsed() / iterations;
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / iterations as usize;
+    println!(
+        "{name}: {average:?} and {allocations} allocations per iteration ({iterations} iterations)"
+    );
+}
+
+/// Generates a deterministic string of the given length which mixes ASCII and non ASCII
+/// characters so the inputs are not trivially comparable byte by byte.
+fn generate(length: usize, seed: u32) -> String {
+    generate_from(
+        &['a', 'b', 'c', 'd', 'e', 'é', 'ñ', 'ß', 'x', 'y', 'z', ' '],
+        length,
+        seed,
+    )
+}
+
+/// Generates a deterministic string of the given length which only uses ASCII characters.
+fn generate_ascii(length: usize, seed: u32) -> String {
+    generate_from(
+        &['a', 'b', 'c', 'd', 'e', 'f', 'g', 'x', 'y', 'z', '_', ' '],
+        length,
+        seed,
+    )
+}
+
+fn generate_from(alphabet: &[char], length: usize, seed: u32) -> String {
+    let mut state = seed;
+    (0..length)
+        .map(|_| {
+            // Small linear congruential generator, we only need to avoid repetition
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            alphabet[(state >> 16) as usize % alphabet.len()]
+        })
+        .collect()
+}
+
+fn main() {
+    let source = generate(1000, 1);
+    let target = generate(1000, 2);
+    bench("levenshtein_distance 1000x1000", 10, || {
+        black_box(levenshtein_distance(black_box(&source), black_box(&target)));
+    });
+    bench("levenshtein_distance_myers 1000x1000", 10, || {
+        black_box(levenshtein_distance_myers(
+            black_box(&source),
+            black_box(&target),
+        ));
+    });
+
+    let query = generate(12, 3);
+    let targets = (0..10_000)
+        .map(|i| generate(12, i))
+        .collect::<Vec<String>>();
+    bench("levenshtein_distance 10k comparisons", 10, || {
+        for target in &targets {
+            black_box(levenshtein_distance(black_box(&query), black_box(target)));
+        }
+    });
+    let mut levenshtein = Levenshtein::new();
+    bench("Levenshtein::distance 10k comparisons", 10, || {
+        for target in &targets {
+            black_box(levenshtein.distance(black_box(&query), black_box(target)));
+        }
+    });
+    let prepared = Query::new(&query);
+    bench("Query::distance 10k comparisons", 10, || {
+        for target in &targets {
+            black_box(black_box(&prepared).distance(black_box(target)));
+        }
+    });
+
+    let query = generate_ascii(12, 3);
+    let targets = (0..10_000)
+        .map(|i| generate_ascii(12, i))
+        .collect::<Vec<String>>();
+    bench("levenshtein_distance ASCII 10k comparisons", 10, || {
+        for target in &targets {
+            black_box(levenshtein_distance(black_box(&query), black_box(target)));
+        }
+    });
+    bench("levenshtein_distance_ascii 10k comparisons", 10, || {
+        for target in &targets {
+            black_box(levenshtein_distance_ascii(
+                black_box(query.as_bytes()),
+                black_box(target.as_bytes()),
+            ));
+        }
+    });
+
+    // Already lowercase, so there's nothing to fold
+    bench(
+        "levenshtein_distance_ignore_case lowercase 10k comparisons",
+        10,
+        || {
+            for target in &targets {
+                black_box(levenshtein_distance_ignore_case(
+                    black_box(&query),
+                    black_box(target),
+                ));
+            }
+        },
+    );
+    bench(
+        "levenshtein_distance_ignore_ascii_case lowercase 10k comparisons",
+        10,
+        || {
+            for target in &targets {
+                black_box(levenshtein_distance_ignore_ascii_case(
+                    black_box(&query),
+                    black_box(target),
+                ));
+            }
+        },
+    );
+
+    let source = generate_ascii(2000, 1);
+    let target = generate_ascii(2000, 2);
+    bench("levenshtein_distance_slice ASCII 2000x2000", 10, || {
+        black_box(levenshtein_distance_slice(
+            black_box(source.as_bytes()),
+            black_box(target.as_bytes()),
+        ));
+    });
+    bench("levenshtein_distance_ascii 2000x2000", 10, || {
+        black_box(levenshtein_distance_ascii(
+            black_box(source.as_bytes()),
+            black_box(target.as_bytes()),
+        ));
+    });
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_borrow)]
+
 use fuzzy_string_distance::levenshtein_distance;
 
 fn main() {
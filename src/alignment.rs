@@ -0,0 +1,229 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The scores for each kind of step in a [smith_waterman] local alignment or a
+/// [needleman_wunsch] global alignment.
+///
+/// Every score is added to the total, so rewards should be positive and penalties negative.
+/// The default scores are 3 for a match, -3 for a mismatch and -2 for a gap, as in the
+/// Wikipedia example of the algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scoring {
+    /// The score for aligning two equal characters, which should be positive
+    pub match_: i32,
+    /// The score for aligning two different characters, which should be negative
+    pub mismatch: i32,
+    /// The score for aligning a character against a gap in the other string, which should be
+    /// negative
+    pub gap: i32,
+}
+
+impl Default for Scoring {
+    fn default() -> Self {
+        Scoring {
+            match_: 3,
+            mismatch: -3,
+            gap: -2,
+        }
+    }
+}
+
+/// Returns the highest score of any local alignment between a substring of the source and a
+/// substring of the target, using the Smith-Waterman algorithm.
+///
+/// Unlike the distances in this crate, this is a similarity score where higher is better. Each
+/// pair of aligned characters adds the match or mismatch score from `scoring`, and each
+/// character aligned against a gap adds the gap score. The score of an alignment is never
+/// allowed to drop below 0, as a poorly matching prefix is better left out of the alignment
+/// entirely, so unrelated strings score 0.
+///
+/// ```
+/// use fuzzy_string_distance::{smith_waterman, Scoring};
+/// let scoring = Scoring { match_: 3, mismatch: -3, gap: -2 };
+/// // GTT-AC aligned to GTTGAC
+/// assert_eq!(13, smith_waterman("TGTTACGG", "GGTTGACTA", scoring));
+/// assert_eq!(0, smith_waterman("abc", "xyz", scoring));
+/// ```
+///
+/// See also:
+/// - [Smith-Waterman algorithm](https://en.wikipedia.org/wiki/Smith%E2%80%93Waterman_algorithm)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn smith_waterman(source: &str, target: &str, scoring: Scoring) -> i32 {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+
+    // We'll have a matrix H of `source` length + 1 rows and `target` length + 1 columns, where
+    // H[i, j] is the best score of any alignment ending at the i'th character of the source and
+    // the j'th character of the target. The first row and column are all 0 as an alignment can
+    // start anywhere. As with Levenshtein distance, we only need to store two rows at a time.
+    let mut scores = vec![0; target.len() + 1];
+    let mut new_scores = vec![0; target.len() + 1];
+    let mut best = 0;
+
+    for source_char in &source {
+        for (j, target_char) in target.iter().enumerate() {
+            // We're calculating H[i + 1, j + 1]
+            let diagonal = scores[j]
+                + if source_char == target_char {
+                    scoring.match_
+                } else {
+                    scoring.mismatch
+                };
+            // Aligning the source character against a gap in the target
+            let up = scores[j + 1] + scoring.gap;
+            // Aligning the target character against a gap in the source
+            let left = new_scores[j] + scoring.gap;
+            // Flooring at 0 starts a new alignment here instead of extending a bad one
+            new_scores[j + 1] = diagonal.max(up).max(left).max(0);
+            best = best.max(new_scores[j + 1]);
+        }
+
+        core::mem::swap(&mut scores, &mut new_scores);
+    }
+
+    best
+}
+
+/// Returns the highest score of any global alignment of the whole source against the whole
+/// target, using the Needleman-Wunsch algorithm.
+///
+/// Like [smith_waterman], this is a similarity score where higher is better, with each pair of
+/// aligned characters adding the match or mismatch score from `scoring` and each character
+/// aligned against a gap adding the gap score. Unlike [smith_waterman], every character of both
+/// strings has to be part of the alignment, and the score can be negative.
+///
+/// ```
+/// use fuzzy_string_distance::{needleman_wunsch, Scoring};
+/// let scoring = Scoring { match_: 1, mismatch: -1, gap: -1 };
+/// // GCATG-CG aligned to G-ATTACA
+/// assert_eq!(0, needleman_wunsch("GCATGCG", "GATTACA", scoring));
+/// ```
+///
+/// This is closely related to [levenshtein_distance](crate::levenshtein_distance). With a match
+/// score of 0 and mismatch and gap scores of -1, the best alignment is the one with the fewest
+/// edits, and the score is exactly the negated Levenshtein distance. Rewarding matches with a
+/// positive score instead favours alignments with more matching characters, which can rank
+/// long strings with a lot in common above short strings with few edits.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, needleman_wunsch, Scoring};
+/// let scoring = Scoring { match_: 0, mismatch: -1, gap: -1 };
+/// assert_eq!(-3, needleman_wunsch("kitten", "sitting", scoring));
+/// assert_eq!(3, levenshtein_distance("kitten", "sitting"));
+/// ```
+///
+/// See also:
+/// - [Needleman-Wunsch algorithm](https://en.wikipedia.org/wiki/Needleman%E2%80%93Wunsch_algorithm)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn needleman_wunsch(source: &str, target: &str, scoring: Scoring) -> i32 {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+
+    // We'll have a matrix F of `source` length + 1 rows and `target` length + 1 columns, where
+    // F[i, j] is the best score of aligning the first i characters of the source with the
+    // first j characters of the target. The first row aligns prefixes of the target against
+    // nothing, so is all gaps, and we only need to store two rows at a time.
+    let mut scores = (0..=target.len())
+        .map(|j| j as i32 * scoring.gap)
+        .collect::<Vec<i32>>();
+    let mut new_scores = vec![0; target.len() + 1];
+
+    for (i, source_char) in source.iter().enumerate() {
+        // Aligning a prefix of the source against nothing is also all gaps
+        new_scores[0] = (i as i32 + 1) * scoring.gap;
+        for (j, target_char) in target.iter().enumerate() {
+            // We're calculating F[i + 1, j + 1]
+            let diagonal = scores[j]
+                + if source_char == target_char {
+                    scoring.match_
+                } else {
+                    scoring.mismatch
+                };
+            let up = scores[j + 1] + scoring.gap;
+            let left = new_scores[j] + scoring.gap;
+            new_scores[j + 1] = diagonal.max(up).max(left);
+        }
+
+        core::mem::swap(&mut scores, &mut new_scores);
+    }
+
+    scores[target.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn common_substring() {
+        let scoring = Scoring {
+            match_: 2,
+            mismatch: -1,
+            gap: -1,
+        };
+        // The common substring rust scores 2 for each of its 4 characters
+        let result = smith_waterman("I love rust!", "rustaceans", scoring);
+        assert_eq!(result, 8);
+        let result = smith_waterman("rust", "rust", scoring);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn default_scoring() {
+        let result = smith_waterman("TGTTACGG", "GGTTGACTA", Scoring::default());
+        assert_eq!(result, 13);
+        let result = smith_waterman("GGTTGACTA", "TGTTACGG", Scoring::default());
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn no_alignment() {
+        let result = smith_waterman("abc", "xyz", Scoring::default());
+        assert_eq!(result, 0);
+        let result = smith_waterman("", "rust", Scoring::default());
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn global_alignment() {
+        let scoring = Scoring {
+            match_: 1,
+            mismatch: -1,
+            gap: -1,
+        };
+        let result = needleman_wunsch("GCATGCG", "GATTACA", scoring);
+        assert_eq!(result, 0);
+        let result = needleman_wunsch("rust", "rust", scoring);
+        assert_eq!(result, 4);
+        let result = needleman_wunsch("", "rust", scoring);
+        assert_eq!(result, -4);
+    }
+
+    #[test]
+    fn global_alignment_is_negated_levenshtein() {
+        let scoring = Scoring {
+            match_: 0,
+            mismatch: -1,
+            gap: -1,
+        };
+        let pairs = [
+            ("kitten", "sitting"),
+            ("", "bug"),
+            ("El delfín español", "Dolphin"),
+        ];
+        for (source, target) in pairs {
+            let result = needleman_wunsch(source, target, scoring);
+            assert_eq!(
+                result,
+                -(crate::levenshtein_distance(source, target) as i32)
+            );
+        }
+    }
+}
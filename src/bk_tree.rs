@@ -0,0 +1,193 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::Levenshtein;
+
+/// A BK-tree of strings, which can find all the strings within a maximum
+/// [levenshtein_distance](crate::levenshtein_distance) of a query without comparing the query
+/// against every string.
+///
+/// Each node in the tree stores a string, and its children are keyed by their distance to that
+/// string. Since Levenshtein distance satisfies the triangle inequality, when searching for
+/// strings within `max_distance` of a query that is `d` away from a node, only the children
+/// keyed between `d - max_distance` and `d + max_distance` can contain matches, so the rest of
+/// the tree can be skipped.
+///
+/// ```
+/// use fuzzy_string_distance::BkTree;
+/// let tree = ["book", "books", "cake", "boo", "cape", "cart"]
+///     .into_iter()
+///     .map(String::from)
+///     .collect::<BkTree>();
+/// assert_eq!(vec![("book", 1), ("books", 2), ("boo", 2)], tree.find("bokk", 2));
+/// ```
+///
+/// See also:
+/// - [BK-tree](https://en.wikipedia.org/wiki/BK-tree)
+#[derive(Clone, Debug, Default)]
+pub struct BkTree {
+    nodes: Vec<Node>,
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    string: String,
+    /// The index of each child node, keyed by its distance to this node's string
+    children: BTreeMap<usize, usize>,
+}
+
+impl BkTree {
+    /// Creates a new empty BkTree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of strings in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if there are no strings in the tree.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Adds a string to the tree. If the tree already contains this string it is not added
+    /// again.
+    pub fn insert(&mut self, string: String) {
+        if self.nodes.is_empty() {
+            self.nodes.push(Node::new(string));
+            return;
+        }
+        let mut levenshtein = Levenshtein::new();
+        let mut current = 0;
+        loop {
+            let distance = levenshtein.distance(&self.nodes[current].string, &string);
+            if distance == 0 {
+                return;
+            }
+            match self.nodes[current].children.get(&distance) {
+                Some(&child) => current = child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::new(string));
+                    self.nodes[current].children.insert(distance, child);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Returns every string in the tree within `max_distance` of the query, along with its
+    /// distance to the query, sorted by ascending distance.
+    ///
+    /// Strings with the same distance to the query are returned in the order they were
+    /// inserted into the tree.
+    pub fn find(&self, query: &str, max_distance: usize) -> Vec<(&str, usize)> {
+        if self.nodes.is_empty() {
+            return Vec::new();
+        }
+        let mut found = Vec::new();
+        let mut levenshtein = Levenshtein::new();
+        let mut to_visit = vec![0];
+        while let Some(current) = to_visit.pop() {
+            let node = &self.nodes[current];
+            let distance = levenshtein.distance(&node.string, query);
+            if distance <= max_distance {
+                found.push((current, distance));
+            }
+            // By the triangle inequality, any string in a child's subtree is exactly the
+            // child's key away from this node's string, so can only be within max_distance of
+            // the query if that key is within max_distance of this node's distance to the query
+            let lowest = distance.saturating_sub(max_distance);
+            let highest = distance.saturating_add(max_distance);
+            to_visit.extend(
+                node.children
+                    .range(lowest..=highest)
+                    .map(|(_, &child)| child),
+            );
+        }
+        // Node indexes are in insertion order, so breaking ties by index keeps strings with the
+        // same distance in the order they were inserted
+        found.sort_unstable_by_key(|&(index, distance)| (distance, index));
+        found
+            .into_iter()
+            .map(|(index, distance)| (self.nodes[index].string.as_str(), distance))
+            .collect()
+    }
+}
+
+impl Node {
+    fn new(string: String) -> Self {
+        Node {
+            string,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+impl Extend<String> for BkTree {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, strings: I) {
+        for string in strings {
+            self.insert(string);
+        }
+    }
+}
+
+impl FromIterator<String> for BkTree {
+    fn from_iter<I: IntoIterator<Item = String>>(strings: I) -> Self {
+        let mut tree = BkTree::new();
+        tree.extend(strings);
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein_distance;
+
+    const WORDS: [&str; 10] = [
+        "book", "books", "cake", "boo", "cape", "cart", "boon", "cook", "rust", "trust",
+    ];
+
+    fn tree() -> BkTree {
+        WORDS.into_iter().map(String::from).collect()
+    }
+
+    #[test]
+    fn finding() {
+        let tree = tree();
+        let result = tree.find("bokk", 1);
+        assert_eq!(result, vec![("book", 1)]);
+        let result = tree.find("rust", 0);
+        assert_eq!(result, vec![("rust", 0)]);
+    }
+
+    #[test]
+    fn same_as_linear_search() {
+        let tree = tree();
+        for query in ["bokk", "cap", "", "rusty", "El delfín español"] {
+            for max_distance in 0..6 {
+                let mut expected = WORDS
+                    .into_iter()
+                    .map(|word| (word, levenshtein_distance(word, query)))
+                    .filter(|&(_, distance)| distance <= max_distance)
+                    .collect::<Vec<(&str, usize)>>();
+                expected.sort_by_key(|&(_, distance)| distance);
+                assert_eq!(tree.find(query, max_distance), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut tree = tree();
+        tree.insert("book".to_string());
+        assert_eq!(tree.len(), WORDS.len());
+        assert!(BkTree::new().is_empty());
+        assert_eq!(BkTree::new().find("book", 10), vec![]);
+    }
+}
@@ -0,0 +1,68 @@
+use alloc::borrow::Cow;
+
+/// Lowercases the ASCII letters A to Z in the string as per [str::to_ascii_lowercase], only
+/// allocating a new string if there are any uppercase ASCII letters to change.
+pub(crate) fn to_ascii_lowercase(string: &str) -> Cow<'_, str> {
+    if string.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        Cow::Owned(string.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(string)
+    }
+}
+
+/// Lowercases the string as per [str::to_lowercase], only allocating a new string if any of its
+/// characters change when lowercased.
+pub(crate) fn to_lowercase(string: &str) -> Cow<'_, str> {
+    if string.chars().all(lowercases_to_itself) {
+        Cow::Borrowed(string)
+    } else {
+        Cow::Owned(string.to_lowercase())
+    }
+}
+
+/// Returns true if lowercasing the character gives back exactly the same character.
+///
+/// Characters which are already lowercase are unaffected by the context sensitive rule for
+/// final sigma in [str::to_lowercase], as that only applies to the uppercase Σ, so a string of
+/// these characters is always its own lowercase.
+pub(crate) fn lowercases_to_itself(character: char) -> bool {
+    if character.is_ascii() {
+        return !character.is_ascii_uppercase();
+    }
+    let mut lowercase = character.to_lowercase();
+    lowercase.next() == Some(character) && lowercase.next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrows_lowercase() {
+        assert!(matches!(
+            to_ascii_lowercase("rust é"),
+            Cow::Borrowed("rust é")
+        ));
+        assert!(matches!(
+            to_lowercase("rust é σς"),
+            Cow::Borrowed("rust é σς")
+        ));
+        assert!(matches!(to_lowercase(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn same_as_std() {
+        for string in [
+            "Rust",
+            "RUST É",
+            "ΣΟΦΊΑ Σ",
+            "İstanbul",
+            "straße",
+            "ǅ",
+            "rust",
+        ] {
+            assert_eq!(to_ascii_lowercase(string), string.to_ascii_lowercase());
+            assert_eq!(to_lowercase(string), string.to_lowercase());
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::levenshtein_distance_within;
+use crate::Levenshtein;
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) between every pair of items,
+/// as a matrix where the entry at `[i][j]` is the distance between `items[i]` and `items[j]`.
+///
+/// Levenshtein distance is symmetric and every item is 0 away from itself, so only the
+/// `n * (n - 1) / 2` pairs above the diagonal are computed, and then mirrored below it.
+///
+/// ```
+/// use fuzzy_string_distance::distance_matrix;
+/// let matrix = distance_matrix(&["rust", "trust", "crab"]);
+/// assert_eq!(
+///     vec![
+///         vec![0, 1, 4],
+///         vec![1, 0, 4],
+///         vec![4, 4, 0],
+///     ],
+///     matrix,
+/// );
+/// ```
+///
+/// The matrix stores all `n * n` distances, so memory grows with the square of the number of
+/// items, and 100,000 items would need tens of gigabytes. The number of comparisons also grows
+/// with the square of the number of items, so for large inputs consider `distance_matrix_par`
+/// with the `rayon` feature enabled to spread the comparisons across threads.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn distance_matrix(items: &[&str]) -> Vec<Vec<usize>> {
+    let mut matrix = vec![vec![0; items.len()]; items.len()];
+    let mut levenshtein = Levenshtein::new();
+    for (i, source) in items.iter().enumerate() {
+        for (j, target) in items.iter().enumerate().skip(i + 1) {
+            let distance = levenshtein.distance(source, target);
+            matrix[i][j] = distance;
+            matrix[j][i] = distance;
+        }
+    }
+    matrix
+}
+
+/// Groups the items into clusters of near identical strings, returning the indexes of the
+/// items in each cluster.
+///
+/// This is a single pass greedy clustering. The first item starts the first cluster and is its
+/// representative. Each following item joins the first cluster whose representative is within
+/// `max_distance` of it, or starts a new cluster as its representative if there is no such
+/// cluster. Clusters are returned in the order they were started, and the indexes within each
+/// cluster are in ascending order, with the representative first.
+///
+/// ```
+/// use fuzzy_string_distance::cluster_by_threshold;
+/// let items = ["color", "colour", "flavor", "flavour"];
+/// assert_eq!(vec![vec![0, 1], vec![2, 3]], cluster_by_threshold(&items, 1));
+/// ```
+///
+/// Items are only compared against the representative of each cluster, not every member, so
+/// this is not a transitive clustering. Two items can end up in different clusters even though
+/// they are within `max_distance` of each other, and the clusters depend on the order of the
+/// items.
+///
+/// ```
+/// use fuzzy_string_distance::cluster_by_threshold;
+/// // card is within 1 of cart, but cart is in the cluster represented by cat
+/// assert_eq!(vec![vec![0, 1], vec![2]], cluster_by_threshold(&["cat", "cart", "card"], 1));
+/// assert_eq!(vec![vec![0, 1, 2]], cluster_by_threshold(&["cart", "cat", "card"], 1));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn cluster_by_threshold(items: &[&str], max_distance: usize) -> Vec<Vec<usize>> {
+    greedy_clusters(items, max_distance)
+}
+
+/// Removes the items which are within `max_distance` of an earlier item that was kept, so only
+/// the first of each group of near duplicates remains.
+///
+/// Each item is compared against every item kept so far, and is dropped if any of them are
+/// within `max_distance` of it. The kept items are the representatives of the clusters from
+/// [cluster_by_threshold], in their original order. This takes `O(n * k)` distance
+/// calculations, where `n` is the number of items and `k` is the number of items kept, though
+/// each calculation stops early once the distance is provably greater than `max_distance`.
+///
+/// ```
+/// use fuzzy_string_distance::dedup_fuzzy;
+/// let items = vec!["colour", "color", "flavour", "colours", "flavor"]
+///     .into_iter()
+///     .map(String::from)
+///     .collect();
+/// assert_eq!(vec!["colour", "flavour"], dedup_fuzzy(items, 1));
+/// ```
+///
+/// As with [cluster_by_threshold], the first occurrence wins, so the result depends on the order
+/// of the items.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn dedup_fuzzy(items: Vec<String>, max_distance: usize) -> Vec<String> {
+    let clusters = greedy_clusters(&items, max_distance);
+    // The first index of each cluster is the item we keep, and since clusters are started in
+    // the order of the items, these are already in ascending order
+    let mut representatives = clusters.into_iter().map(|cluster| cluster[0]).peekable();
+    items
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, _)| representatives.next_if_eq(&i).is_some())
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// Groups the items into clusters where each item is within `max_distance` of the first item
+/// in its cluster, as per [cluster_by_threshold].
+fn greedy_clusters<S: AsRef<str>>(items: &[S], max_distance: usize) -> Vec<Vec<usize>> {
+    let mut clusters: Vec<Vec<usize>> = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        // The first index of each cluster is its representative
+        let cluster = clusters.iter_mut().find(|cluster| {
+            let representative = items[cluster[0]].as_ref();
+            levenshtein_distance_within(representative, item.as_ref(), max_distance).is_some()
+        });
+        match cluster {
+            Some(cluster) => cluster.push(i),
+            None => clusters.push(vec![i]),
+        }
+    }
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein_distance;
+
+    #[test]
+    fn symmetric_matrix() {
+        let items = ["kitten", "sitting", "mitten", "", "El delfín español"];
+        let matrix = distance_matrix(&items);
+        for (i, source) in items.iter().enumerate() {
+            for (j, target) in items.iter().enumerate() {
+                assert_eq!(matrix[i][j], levenshtein_distance(source, target));
+            }
+        }
+    }
+
+    #[test]
+    fn empty_matrix() {
+        let matrix = distance_matrix(&[]);
+        assert_eq!(matrix, Vec::<Vec<usize>>::new());
+        let matrix = distance_matrix(&["rust"]);
+        assert_eq!(matrix, vec![vec![0]]);
+    }
+
+    #[test]
+    fn clusters() {
+        let items = ["color", "colour", "flavor", "flavour"];
+        let result = cluster_by_threshold(&items, 1);
+        assert_eq!(result, vec![vec![0, 1], vec![2, 3]]);
+        let result = cluster_by_threshold(&items, 0);
+        assert_eq!(result, vec![vec![0], vec![1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn clusters_first_representative() {
+        // colr is within 1 of color but 2 away from colour, which represents the cluster
+        let items = ["colour", "color", "colr", "flavour"];
+        let result = cluster_by_threshold(&items, 1);
+        assert_eq!(result, vec![vec![0, 1], vec![2], vec![3]]);
+        let result = cluster_by_threshold(&[], 1);
+        assert_eq!(result, Vec::<Vec<usize>>::new());
+    }
+
+    #[test]
+    fn dedup() {
+        let items = ["colour", "color", "flavour", "colours", "flavor", "colour"];
+        let result = dedup_fuzzy(items.map(String::from).to_vec(), 1);
+        assert_eq!(result, vec!["colour", "flavour"]);
+        let result = dedup_fuzzy(items.map(String::from).to_vec(), 0);
+        assert_eq!(
+            result,
+            vec!["colour", "color", "flavour", "colours", "flavor"]
+        );
+    }
+
+    #[test]
+    fn dedup_first_wins() {
+        let items = ["color", "colour", "colours"];
+        let result = dedup_fuzzy(items.map(String::from).to_vec(), 1);
+        // colours is within 1 of colour, but colour was dropped in favour of color
+        assert_eq!(result, vec!["color", "colours"]);
+        assert_eq!(dedup_fuzzy(Vec::new(), 1), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,166 @@
+#[cfg(feature = "unicode-normalization")]
+use alloc::borrow::Cow;
+
+#[cfg(feature = "unicode-normalization")]
+use crate::normalization::{normalize, Normalization};
+
+/// A bundle of options for comparing strings, which picks the right distance function for the
+/// combination of options so you don't have to.
+///
+/// Create one with [Comparator::new], then call [Comparator::distance] as many times as you
+/// need. A default `Comparator` is the same as [levenshtein_distance](crate::levenshtein_distance).
+///
+/// ```
+/// use fuzzy_string_distance::Comparator;
+/// let comparator = Comparator::new().ignore_case(true).local(true).build();
+/// assert_eq!(0, comparator.distance("rust", "I love Rust!"));
+/// assert_eq!(1, comparator.distance("rusty", "I love Rust!"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Comparator {
+    ignore_case: bool,
+    local: bool,
+    #[cfg(feature = "unicode-normalization")]
+    normalization: Option<Normalization>,
+}
+
+/// A builder for a [Comparator], where every option is off by default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ComparatorBuilder {
+    comparator: Comparator,
+}
+
+impl Comparator {
+    /// Creates a new [ComparatorBuilder] with every option turned off.
+    ///
+    /// Call [ComparatorBuilder::build] once the options are set to get the Comparator.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> ComparatorBuilder {
+        ComparatorBuilder::new()
+    }
+
+    /// Creates a new [ComparatorBuilder] with every option turned off, the same as
+    /// [Comparator::new].
+    pub fn builder() -> ComparatorBuilder {
+        ComparatorBuilder::new()
+    }
+
+    /// Returns the distance from the source string to the target string according to the
+    /// options this Comparator was built with.
+    ///
+    /// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+    /// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+    /// still does not compare grapheme clusters.
+    pub fn distance(&self, source: &str, target: &str) -> usize {
+        // Normalize first so that the case and distance functions see the same characters for
+        // visually identical strings
+        #[cfg(feature = "unicode-normalization")]
+        let (source, target) = match self.normalization {
+            Some(form) => (
+                Cow::Owned(normalize(source, form)),
+                Cow::Owned(normalize(target, form)),
+            ),
+            None => (Cow::Borrowed(source), Cow::Borrowed(target)),
+        };
+        #[cfg(feature = "unicode-normalization")]
+        let (source, target) = (source.as_ref(), target.as_ref());
+        match (self.ignore_case, self.local) {
+            (false, false) => crate::levenshtein_distance(source, target),
+            (true, false) => crate::levenshtein_distance_ignore_case(source, target),
+            (false, true) => crate::local_levenshtein_distance(source, target),
+            (true, true) => crate::local_levenshtein_distance_ignore_case(source, target),
+        }
+    }
+}
+
+impl ComparatorBuilder {
+    /// Creates a new ComparatorBuilder with every option turned off.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether differences in case are ignored, as per
+    /// [levenshtein_distance_ignore_case](crate::levenshtein_distance_ignore_case).
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.comparator.ignore_case = ignore_case;
+        self
+    }
+
+    /// Sets whether the source is matched against any substring of the target, as per
+    /// [local_levenshtein_distance](crate::local_levenshtein_distance).
+    pub fn local(mut self, local: bool) -> Self {
+        self.comparator.local = local;
+        self
+    }
+
+    /// Sets the Unicode normalization form both strings are converted to before comparing
+    /// them.
+    ///
+    /// This is only available with the `unicode-normalization` feature enabled.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn normalize(mut self, form: Normalization) -> Self {
+        self.comparator.normalization = Some(form);
+        self
+    }
+
+    /// Creates the Comparator with the options set so far.
+    pub fn build(self) -> Comparator {
+        self.comparator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_options() {
+        let comparator = Comparator::new().build();
+        assert_eq!(comparator, Comparator::default());
+        assert_eq!(comparator, Comparator::builder().build());
+        let result = comparator.distance("kitten", "sitting");
+        assert_eq!(result, 3);
+        let result = comparator.distance("Rust", "rust");
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn combined_options() {
+        let pairs = [
+            ("RUST", "I love rust!"),
+            ("Straße", "STRASSE"),
+            ("long", "A long sentence"),
+        ];
+        for (source, target) in pairs {
+            for ignore_case in [false, true] {
+                for local in [false, true] {
+                    let comparator = Comparator::builder()
+                        .ignore_case(ignore_case)
+                        .local(local)
+                        .build();
+                    let expected = match (ignore_case, local) {
+                        (false, false) => crate::levenshtein_distance(source, target),
+                        (true, false) => crate::levenshtein_distance_ignore_case(source, target),
+                        (false, true) => crate::local_levenshtein_distance(source, target),
+                        (true, true) => {
+                            crate::local_levenshtein_distance_ignore_case(source, target)
+                        }
+                    };
+                    assert_eq!(comparator.distance(source, target), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-normalization")]
+    fn normalized() {
+        let comparator = Comparator::builder()
+            .ignore_case(true)
+            .normalize(Normalization::Nfc)
+            .build();
+        // Precomposed é against e followed by a combining acute accent
+        let result = comparator.distance("CAF\u{c9}", "cafe\u{301}");
+        assert_eq!(result, 0);
+    }
+}
@@ -0,0 +1,377 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Returns the minimum number of single character insertions, deletions, substitutions or
+/// transpositions of two adjacent characters required to convert the source string to the target
+/// string, known as the optimal string alignment distance or restricted Damerau-Levenshtein
+/// distance.
+///
+/// This is like [levenshtein_distance](crate::levenshtein_distance), except that swapping two
+/// neighbouring characters counts as a single edit instead of two substitutions, which is a more
+/// natural model for typos where two keys were pressed in the wrong order.
+///
+/// ```
+/// use fuzzy_string_distance::{damerau_levenshtein_distance, levenshtein_distance};
+/// assert_eq!(1, damerau_levenshtein_distance("teh", "the")); // swap e and h
+/// assert_eq!(2, levenshtein_distance("teh", "the")); // substitute both e and h
+/// ```
+///
+/// This is the restricted variant, which does not allow a substring to be edited more than once,
+/// so characters that have been transposed can't then have another character inserted between
+/// them. See [damerau_levenshtein_distance_unrestricted] for a version without this restriction.
+///
+/// See also:
+/// - [Damerau-Levenshtein distance](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn damerau_levenshtein_distance(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let target_chars = target.len();
+    let source_chars = source.len();
+    // As with Levenshtein distance, if either input is empty then the shortest transformation
+    // is all deletions or insertions
+    if source.is_empty() {
+        return target_chars;
+    }
+    if target.is_empty() {
+        return source_chars;
+    }
+
+    // We'll have the same matrix A of `source` length + 1 rows and `target` length + 1 columns
+    // as Levenshtein distance, but a transposition looks back two characters in each string,
+    // so we need to keep the row from two prefixes ago as well as the previous row.
+    let mut two_rows_ago = vec![0; target_chars + 1];
+    let mut edit_distances = (0..=target_chars).collect::<Vec<usize>>();
+    let mut new_edit_distances = vec![0; target_chars + 1];
+
+    for (i, source_char) in source.iter().enumerate() {
+        new_edit_distances[0] = i + 1;
+
+        for (j, target_char) in target.iter().enumerate() {
+            // We're calculating the edit distance for A[i + 1, j + 1] and the first three
+            // options are the same as Levenshtein distance
+            let deletion = edit_distances[j + 1] + 1;
+            let insertion = new_edit_distances[j] + 1;
+            let substitution = if source_char == target_char {
+                edit_distances[j]
+            } else {
+                edit_distances[j] + 1
+            };
+            let mut distance = core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+
+            // If the last two characters of both prefixes are the same pair but swapped, we can
+            // transpose them, which costs 1 more than the distance at A[i - 1, j - 1] where both
+            // prefixes were two characters shorter
+            if i > 0 && j > 0 && *source_char == target[j - 1] && source[i - 1] == *target_char {
+                distance = core::cmp::min(distance, two_rows_ago[j - 1] + 1);
+            }
+
+            new_edit_distances[j + 1] = distance;
+        }
+
+        // Shuffle the rows along, the oldest row's buffer gets overwritten by the next row
+        core::mem::swap(&mut two_rows_ago, &mut edit_distances);
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+
+    edit_distances[target_chars]
+}
+
+/// Returns the minimum number of single character insertions, deletions, substitutions or
+/// transpositions of two adjacent characters required to convert the source string to the target
+/// string, known as the Damerau-Levenshtein distance.
+///
+/// Unlike [damerau_levenshtein_distance], this allows substrings to be edited more than once, so
+/// two characters can be transposed and then have more characters inserted between them. This
+/// makes the distance a true metric which satisfies the triangle inequality, at the cost of
+/// needing to store the whole matrix of edit distances and track the last position each character
+/// was seen in.
+///
+/// ```
+/// use fuzzy_string_distance::{damerau_levenshtein_distance, damerau_levenshtein_distance_unrestricted};
+/// // CA -> AC -> ABC, transposing and then inserting B between the transposed characters
+/// assert_eq!(2, damerau_levenshtein_distance_unrestricted("CA", "ABC"));
+/// // CA -> A -> AB -> ABC, as the restricted version can't edit the transposed characters again
+/// assert_eq!(3, damerau_levenshtein_distance("CA", "ABC"));
+/// ```
+///
+/// See also:
+/// - [Damerau-Levenshtein distance](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn damerau_levenshtein_distance_unrestricted(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let target_chars = target.len();
+    let source_chars = source.len();
+    if source.is_empty() {
+        return target_chars;
+    }
+    if target.is_empty() {
+        return source_chars;
+    }
+
+    // We need a matrix A of `source` length + 2 rows and `target` length + 2 columns, as a
+    // transposition can jump back an arbitrary number of rows and columns, we can't get away
+    // with only storing a few rows at a time. The extra row and column at the start hold a
+    // distance larger than any real distance, which guards the transpositions from reaching
+    // before the start of either string.
+    // A[1, 1] is therefore "" to "" which is 0, and A[source length + 1, target length + 1] is
+    // the edit distance from source to target.
+    let width = target_chars + 2;
+    let index = |i: usize, j: usize| i * width + j;
+    let maximum = source_chars + target_chars;
+    let mut edit_distances = vec![0; (source_chars + 2) * width];
+    edit_distances[index(0, 0)] = maximum;
+    for i in 0..=source_chars {
+        edit_distances[index(i + 1, 0)] = maximum;
+        edit_distances[index(i + 1, 1)] = i;
+    }
+    for j in 0..=target_chars {
+        edit_distances[index(0, j + 1)] = maximum;
+        edit_distances[index(1, j + 1)] = j;
+    }
+
+    // The last row that each character was seen in the source, where row 0 means not seen yet
+    let mut last_row_seen: BTreeMap<char, usize> = BTreeMap::new();
+
+    for (i, source_char) in source.iter().enumerate() {
+        // The row in the matrix for this prefix of `source`, using 1 based character counting
+        let row = i + 1;
+        // The last column in this row where the characters matched
+        let mut last_matching_column = 0;
+
+        for (j, target_char) in target.iter().enumerate() {
+            let column = j + 1;
+            // The last row where the current target character was seen in the source, and the
+            // last column where the current source character was seen in the target. If we
+            // transpose these two positions we'll need to delete everything in the source in
+            // between and insert everything in the target in between.
+            let k = last_row_seen.get(target_char).copied().unwrap_or(0);
+            let l = last_matching_column;
+            let substitution_cost = if source_char == target_char {
+                last_matching_column = column;
+                0
+            } else {
+                1
+            };
+
+            let substitution = edit_distances[index(row, column)] + substitution_cost;
+            let insertion = edit_distances[index(row + 1, column)] + 1;
+            let deletion = edit_distances[index(row, column + 1)] + 1;
+            let transposition = edit_distances[index(k, l)] + (row - k - 1) + 1 + (column - l - 1);
+
+            edit_distances[index(row + 1, column + 1)] = core::cmp::min(
+                core::cmp::min(substitution, transposition),
+                core::cmp::min(insertion, deletion),
+            );
+        }
+
+        last_row_seen.insert(*source_char, row);
+    }
+
+    edit_distances[index(source_chars + 1, target_chars + 1)]
+}
+
+/// The cost of each kind of single character edit, for use with [osa_distance_weighted].
+///
+/// This is like [Costs](crate::Costs) with an extra cost for transposing two adjacent
+/// characters. The default costs are 1 for every operation, which gives the same results as
+/// [damerau_levenshtein_distance].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OsaCosts {
+    /// The cost of inserting a character into the source
+    pub insert: usize,
+    /// The cost of deleting a character from the source
+    pub delete: usize,
+    /// The cost of substituting a character in the source for a different character
+    pub substitute: usize,
+    /// The cost of swapping two adjacent characters in the source
+    pub transpose: usize,
+}
+
+impl Default for OsaCosts {
+    fn default() -> Self {
+        OsaCosts {
+            insert: 1,
+            delete: 1,
+            substitute: 1,
+            transpose: 1,
+        }
+    }
+}
+
+/// Returns the minimum total cost of single character insertions, deletions, substitutions or
+/// transpositions of two adjacent characters required to convert the source string to the target
+/// string, where each kind of edit has the cost given in `costs`.
+///
+/// With all costs set to 1 this is the same as [damerau_levenshtein_distance], and has the same
+/// restriction that characters which have been transposed can't be edited again. Transpositions
+/// are only chosen when they're cheaper than the other edits that could swap the characters, so
+/// making them cost more than two substitutions gives the same results as
+/// [levenshtein_distance_weighted](crate::levenshtein_distance_weighted).
+///
+/// ```
+/// use fuzzy_string_distance::{osa_distance_weighted, OsaCosts};
+/// let costs = OsaCosts { insert: 2, delete: 2, substitute: 2, transpose: 1 };
+/// assert_eq!(1, osa_distance_weighted("teh", "the", costs)); // swap e and h
+/// assert_eq!(2, osa_distance_weighted("teh", "ten", costs)); // substitute h
+/// ```
+///
+/// See also:
+/// - [Damerau-Levenshtein distance](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn osa_distance_weighted(source: &str, target: &str, costs: OsaCosts) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let target_chars = target.len();
+
+    // The same rows as damerau_levenshtein_distance, but each edit we make adds its own cost
+    // instead of 1. As with weighted Levenshtein distance, we can't swap the inputs around as
+    // that would swap insertions and deletions.
+    let mut two_rows_ago = vec![0; target_chars + 1];
+    let mut edit_distances = (0..=target_chars)
+        .map(|j| j * costs.insert)
+        .collect::<Vec<usize>>();
+    let mut new_edit_distances = vec![0; target_chars + 1];
+
+    for (i, source_char) in source.iter().enumerate() {
+        new_edit_distances[0] = (i + 1) * costs.delete;
+
+        for (j, target_char) in target.iter().enumerate() {
+            let deletion = edit_distances[j + 1] + costs.delete;
+            let insertion = new_edit_distances[j] + costs.insert;
+            let substitution = if source_char == target_char {
+                edit_distances[j]
+            } else {
+                edit_distances[j] + costs.substitute
+            };
+            let mut distance = core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+
+            if i > 0 && j > 0 && *source_char == target[j - 1] && source[i - 1] == *target_char {
+                distance = core::cmp::min(distance, two_rows_ago[j - 1] + costs.transpose);
+            }
+
+            new_edit_distances[j + 1] = distance;
+        }
+
+        core::mem::swap(&mut two_rows_ago, &mut edit_distances);
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+
+    edit_distances[target_chars]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transposition() {
+        let result = damerau_levenshtein_distance("teh", "the");
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn same_as_levenshtein_without_transpositions() {
+        let result = damerau_levenshtein_distance("kitten", "sitting");
+        assert_eq!(result, 3);
+        let result = damerau_levenshtein_distance("", "rust");
+        assert_eq!(result, 4);
+        let result = damerau_levenshtein_distance("bug", "");
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn restricted_edits() {
+        // Can't transpose CA to AC and then insert B between them
+        let result = damerau_levenshtein_distance("CA", "ABC");
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn unrestricted_edits() {
+        // Can transpose CA to AC and then insert B between them
+        let result = damerau_levenshtein_distance_unrestricted("CA", "ABC");
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn unrestricted_same_as_restricted() {
+        let pairs = [
+            ("teh", "the"),
+            ("kitten", "sitting"),
+            ("", "rust"),
+            ("bug", ""),
+            ("El delfín español", "Dolphin"),
+        ];
+        for (source, target) in pairs {
+            assert_eq!(
+                damerau_levenshtein_distance_unrestricted(source, target),
+                damerau_levenshtein_distance(source, target),
+            );
+        }
+    }
+
+    #[test]
+    fn weighted_default_costs() {
+        let pairs = [
+            ("teh", "the"),
+            ("kitten", "sitting"),
+            ("", "rust"),
+            ("bug", ""),
+            ("CA", "ABC"),
+        ];
+        for (source, target) in pairs {
+            assert_eq!(
+                osa_distance_weighted(source, target, OsaCosts::default()),
+                damerau_levenshtein_distance(source, target),
+            );
+        }
+    }
+
+    #[test]
+    fn weighted_transpositions() {
+        let mut costs = OsaCosts {
+            insert: 5,
+            delete: 5,
+            substitute: 2,
+            transpose: 1,
+        };
+        // Transposing is cheaper than two substitutions, so we swap a and b
+        let result = osa_distance_weighted("ab", "ba", costs);
+        assert_eq!(result, 1);
+        // Transposing is more expensive than two substitutions, so we substitute both instead
+        costs.transpose = 5;
+        let result = osa_distance_weighted("ab", "ba", costs);
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn weighted_insertions_and_deletions() {
+        let costs = OsaCosts {
+            insert: 1,
+            delete: 3,
+            substitute: 1,
+            transpose: 1,
+        };
+        let result = osa_distance_weighted("", "rust", costs);
+        assert_eq!(result, 4);
+        let result = osa_distance_weighted("bug", "", costs);
+        assert_eq!(result, 9);
+        let result = osa_distance_weighted("cart", "cat", costs);
+        assert_eq!(result, 3);
+        let result = osa_distance_weighted("cat", "cart", costs);
+        assert_eq!(result, 1);
+    }
+}
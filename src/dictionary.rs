@@ -0,0 +1,233 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A list of words to fuzzily search, such as the commands of a CLI or the entries of a
+/// spellchecker's word list.
+///
+/// This wraps up the free functions such as [k_nearest](crate::k_nearest) and
+/// [best_match](crate::best_match) for the common case of searching the same list of words
+/// many times, so the words only need collecting once.
+///
+/// ```
+/// use fuzzy_string_distance::Dictionary;
+/// let dictionary = ["install", "uninstall", "update", "list"]
+///     .into_iter()
+///     .map(String::from)
+///     .collect::<Dictionary>();
+/// assert_eq!(Some(("install", 1)), dictionary.best("instal"));
+/// assert_eq!(vec![("install", 1), ("uninstall", 3)], dictionary.nearest("instal", 2));
+/// assert_eq!(vec![("install", 1)], dictionary.within("instal", 2));
+/// ```
+///
+/// Searches currently compare the query against every word, so take time proportional to the
+/// number of words. For very large word lists with a small maximum distance a
+/// [BkTree](crate::BkTree) can skip most of the comparisons.
+///
+/// The [metaphone](crate::metaphone) code of each word is computed when it is added, so that
+/// [Dictionary::nearest_phonetic] can look up the words which sound like the query without
+/// comparing against every word. This makes adding words a little slower and keeps a second,
+/// shorter copy of each word in memory.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+#[derive(Clone, Debug, Default)]
+pub struct Dictionary {
+    words: Vec<String>,
+    /// The ids of the words with each metaphone code, in ascending order
+    phonetic: BTreeMap<String, Vec<usize>>,
+}
+
+impl Dictionary {
+    /// Creates a new empty Dictionary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns true if there are no words in the dictionary.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Adds a word to the dictionary.
+    pub fn insert(&mut self, word: String) {
+        let code = crate::metaphone(&word);
+        // Words with no code never sound like anything, so don't need indexing
+        if !code.is_empty() {
+            // Ids only ever go up, so pushing keeps each list in ascending order
+            self.phonetic
+                .entry(code)
+                .or_default()
+                .push(self.words.len());
+        }
+        self.words.push(word);
+    }
+
+    /// Returns the word with the smallest [levenshtein_distance](crate::levenshtein_distance)
+    /// to the query, along with that distance, or `None` if the dictionary is empty.
+    ///
+    /// If several words are equally close to the query, the first of them to be added is
+    /// returned.
+    pub fn best(&self, query: &str) -> Option<(&str, usize)> {
+        crate::best_match(query, self.words())
+    }
+
+    /// Returns up to `k` of the words with the smallest
+    /// [levenshtein_distance](crate::levenshtein_distance) to the query, along with their
+    /// distances, sorted by ascending distance.
+    ///
+    /// Words that are equally close to the query are returned in the order they were added.
+    pub fn nearest(&self, query: &str, k: usize) -> Vec<(&str, usize)> {
+        crate::k_nearest(query, self.words(), k)
+    }
+
+    /// Returns every word within `max_distance` of the query, along with its distance to the
+    /// query, sorted by ascending distance.
+    ///
+    /// Words that are equally close to the query are returned in the order they were added.
+    pub fn within(&self, query: &str, max_distance: usize) -> Vec<(&str, usize)> {
+        let mut found = self
+            .words()
+            .filter_map(|word| {
+                crate::levenshtein_distance_within(query, word, max_distance)
+                    .map(|distance| (word, distance))
+            })
+            .collect::<Vec<(&str, usize)>>();
+        // The sort is stable, so ties stay in the order they were added
+        found.sort_by_key(|&(_, distance)| distance);
+        found
+    }
+
+    /// Returns up to `k` of the words with the same [metaphone](crate::metaphone) code as the
+    /// query, along with their [levenshtein_distance](crate::levenshtein_distance) to the query,
+    /// sorted by ascending distance.
+    ///
+    /// This first narrows the dictionary down to the words which sound like the query, and then
+    /// ranks them by how many edits they are from it, so a word that is spelled differently but
+    /// pronounced the same is preferred over a word that is spelled similarly but pronounced
+    /// differently. Only the words sharing the query's code are compared against it.
+    ///
+    /// ```
+    /// use fuzzy_string_distance::Dictionary;
+    /// let dictionary = ["Stephen", "Steve", "Simon", "Stefan", "Steven"]
+    ///     .into_iter()
+    ///     .map(String::from)
+    ///     .collect::<Dictionary>();
+    /// assert_eq!(vec![("Steven", 1), ("Stefan", 2)], dictionary.nearest_phonetic("Stevn", 2));
+    /// // Steve is spelled closer to the query, but sounds different
+    /// assert_eq!(vec![("Steve", 1), ("Steven", 1)], dictionary.nearest("Stevn", 2));
+    /// ```
+    ///
+    /// Words that are equally close to the query are returned in the order they were added. A
+    /// query with no ASCII letters has no code, so doesn't sound like any words.
+    ///
+    /// Metaphone's rules are based on English pronunciation and ignore any letters outside of
+    /// A to Z, so this is only useful for English words and names. For other languages it can
+    /// filter out words that sound the same and keep words that don't.
+    pub fn nearest_phonetic(&self, query: &str, k: usize) -> Vec<(&str, usize)> {
+        let candidates = self
+            .phonetic
+            .get(&crate::metaphone(query))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        crate::k_nearest(
+            query,
+            candidates.iter().map(|&id| self.words[id].as_str()),
+            k,
+        )
+    }
+
+    fn words(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(String::as_str)
+    }
+}
+
+impl Extend<String> for Dictionary {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, words: I) {
+        for word in words {
+            self.insert(word);
+        }
+    }
+}
+
+impl FromIterator<String> for Dictionary {
+    fn from_iter<I: IntoIterator<Item = String>>(words: I) -> Self {
+        let mut dictionary = Dictionary::new();
+        dictionary.extend(words);
+        dictionary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary() -> Dictionary {
+        ["book", "books", "cake", "boo", "cape", "cart"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn best() {
+        let dictionary = dictionary();
+        assert_eq!(dictionary.best("cpe"), Some(("cape", 1)));
+        // book and boo are both 1 away, pick the first added
+        assert_eq!(dictionary.best("bok"), Some(("book", 1)));
+        assert_eq!(Dictionary::new().best("bok"), None);
+    }
+
+    #[test]
+    fn nearest() {
+        let dictionary = dictionary();
+        let result = dictionary.nearest("bokk", 3);
+        assert_eq!(result, vec![("book", 1), ("books", 2), ("boo", 2)]);
+        assert_eq!(dictionary.nearest("bokk", 0), Vec::new());
+        assert_eq!(dictionary.nearest("bokk", 10).len(), 6);
+    }
+
+    #[test]
+    fn within() {
+        let mut dictionary = dictionary();
+        assert_eq!(dictionary.len(), 6);
+        let result = dictionary.within("bokk", 2);
+        assert_eq!(result, vec![("book", 1), ("books", 2), ("boo", 2)]);
+        // Matches a BkTree of the same words
+        let tree = dictionary
+            .words()
+            .map(String::from)
+            .collect::<crate::BkTree>();
+        assert_eq!(tree.find("cart", 3), dictionary.within("cart", 3));
+        dictionary.insert("bokk".to_string());
+        assert_eq!(dictionary.within("bokk", 0), vec![("bokk", 0)]);
+    }
+
+    #[test]
+    fn nearest_phonetic() {
+        let dictionary = ["knight", "kite", "nite", "night", "nate", "knot"]
+            .into_iter()
+            .map(String::from)
+            .collect::<Dictionary>();
+        // kite is only 1 edit from knite, but doesn't sound like it
+        let result = dictionary.nearest_phonetic("knite", 3);
+        assert_eq!(result, vec![("nite", 1), ("nate", 2), ("knot", 2)]);
+        let result = dictionary.nearest_phonetic("knite", 10);
+        assert_eq!(result.len(), 5);
+        assert_eq!(dictionary.nearest_phonetic("smith", 3), Vec::new());
+    }
+
+    #[test]
+    fn nearest_phonetic_no_code() {
+        let mut dictionary = dictionary();
+        dictionary.insert("123".to_string());
+        assert_eq!(dictionary.nearest_phonetic("123", 3), Vec::new());
+        assert_eq!(dictionary.nearest("123", 1), vec![("123", 0)]);
+    }
+}
@@ -0,0 +1,69 @@
+use alloc::string::String;
+
+/// Fuzzy equality, where two values are equal if they are within a tolerance of each other.
+///
+/// This is like [PartialEq], but for generic code that should accept values which are close
+/// rather than exactly the same. For strings, the tolerance is the maximum
+/// [levenshtein_distance](crate::levenshtein_distance) at which two strings are still considered
+/// equal, so a tolerance of 0 is the same as `==`.
+///
+/// ```
+/// use fuzzy_string_distance::FuzzyEq;
+/// assert!("color".fuzzy_eq("colour", 1));
+/// assert!(!"color".fuzzy_eq("colours", 1));
+///
+/// // Works with anything implementing the trait
+/// fn contains_fuzzy<T: FuzzyEq>(items: &[T], item: &T, tolerance: usize) -> bool {
+///     items.iter().any(|other| other.fuzzy_eq(item, tolerance))
+/// }
+/// let words = vec![String::from("grey"), String::from("colour")];
+/// assert!(contains_fuzzy(&words, &String::from("gray"), 1));
+/// ```
+///
+/// Comparing stops as soon as the distance is known to be over the tolerance, as per
+/// [levenshtein_distance_within](crate::levenshtein_distance_within), so this is much cheaper
+/// than calculating the full distance when the strings are very different. Like the distance it
+/// is based on, fuzzy equality is symmetric but not transitive. For instance "ab" is within 1
+/// edit of both "a" and "abc", but "a" and "abc" are 2 edits apart.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub trait FuzzyEq {
+    /// Returns true if this value is within `tolerance` of the other value.
+    fn fuzzy_eq(&self, other: &Self, tolerance: usize) -> bool;
+}
+
+impl FuzzyEq for str {
+    fn fuzzy_eq(&self, other: &Self, tolerance: usize) -> bool {
+        crate::levenshtein_distance_within(self, other, tolerance).is_some()
+    }
+}
+
+impl FuzzyEq for String {
+    fn fuzzy_eq(&self, other: &Self, tolerance: usize) -> bool {
+        self.as_str().fuzzy_eq(other, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_tolerance() {
+        assert!("color".fuzzy_eq("colour", 1));
+        assert!("colour".fuzzy_eq("color", 1));
+        assert!(!"color".fuzzy_eq("colour", 0));
+        assert!("kitten".fuzzy_eq("sitting", 3));
+        assert!(!"kitten".fuzzy_eq("sitting", 2));
+    }
+
+    #[test]
+    fn zero_tolerance_is_equality() {
+        for (a, b) in [("rust", "rust"), ("rust", "Rust"), ("", ""), ("", "a")] {
+            assert_eq!(a.fuzzy_eq(b, 0), a == b);
+            assert_eq!(String::from(a).fuzzy_eq(&String::from(b), 0), a == b);
+        }
+    }
+}
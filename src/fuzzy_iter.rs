@@ -0,0 +1,83 @@
+use crate::Levenshtein;
+
+/// Iterator adaptors for the fuzzy comparisons in this crate, implemented for every iterator
+/// of string-like items.
+///
+/// This lets a search be written as part of an iterator pipeline, without collecting the
+/// candidates first. The trait needs to be in scope to call its methods.
+///
+/// ```
+/// use fuzzy_string_distance::FuzzyIterator;
+/// let commands = ["install", "uninstall", "update", "list", "--help"];
+/// let closest = commands
+///     .iter()
+///     .filter(|command| !command.starts_with('-'))
+///     .min_by_distance_to("instal");
+/// assert_eq!(Some((&"install", 1)), closest);
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub trait FuzzyIterator: Iterator {
+    /// Returns the item with the smallest [levenshtein_distance](crate::levenshtein_distance) to
+    /// the query, along with that distance, or `None` if the iterator is empty.
+    ///
+    /// Each item's distance is only calculated once, and only the closest item so far is kept,
+    /// so this never collects the items. If several items are equally close to the query, the
+    /// first of them is returned.
+    fn min_by_distance_to(self, query: &str) -> Option<(Self::Item, usize)>;
+}
+
+impl<I> FuzzyIterator for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    fn min_by_distance_to(self, query: &str) -> Option<(Self::Item, usize)> {
+        let mut levenshtein = Levenshtein::new();
+        let mut closest: Option<(Self::Item, usize)> = None;
+        for item in self {
+            let distance = levenshtein.distance(query, item.as_ref());
+            // Only replace the closest item with strictly closer ones, so the first of any
+            // ties is kept
+            let closer = match closest {
+                Some((_, closest)) => distance < closest,
+                None => true,
+            };
+            if closer {
+                closest = Some((item, distance));
+            }
+        }
+        closest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn lazy_iterator() {
+        let words = ["kitten", "sitting", "mitten", "fitting"];
+        // The candidates are only generated as they're compared
+        let result = words
+            .iter()
+            .map(|word| String::from(*word) + "s")
+            .min_by_distance_to("sittings");
+        assert_eq!(result, Some((String::from("sittings"), 0)));
+        let result = words.iter().min_by_distance_to("bitten");
+        assert_eq!(result, Some((&"kitten", 1)));
+    }
+
+    #[test]
+    fn empty_iterator() {
+        let result = core::iter::empty::<&str>().min_by_distance_to("rust");
+        assert_eq!(result, None);
+        // Same as best_match
+        let words = ["trust", "rusty", "crust"];
+        let result = words.into_iter().min_by_distance_to("rust");
+        assert_eq!(result, crate::best_match("rust", words));
+    }
+}
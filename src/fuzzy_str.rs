@@ -0,0 +1,92 @@
+/// Method syntax for the fuzzy comparisons in this crate, implemented for [str].
+///
+/// Each method delegates to the free function of the same name, so `a.levenshtein(b)` is the
+/// same as `levenshtein_distance(a, b)`. This can read better in chains of method calls. The
+/// trait needs to be in scope to call its methods.
+///
+/// ```
+/// use fuzzy_string_distance::FuzzyStr;
+/// assert_eq!(1, "rust".levenshtein("rusty"));
+/// assert_eq!(0, "Rust".levenshtein_ignore_ascii_case("rust"));
+/// assert_eq!(0, "rust".local_levenshtein("I love rust!"));
+/// assert_eq!(0.8, "rust".similarity_ratio("rusty"));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub trait FuzzyStr {
+    /// Returns the [levenshtein_distance](crate::levenshtein_distance) from this string to the
+    /// target.
+    fn levenshtein(&self, target: &str) -> usize;
+
+    /// Returns the
+    /// [levenshtein_distance_ignore_ascii_case](crate::levenshtein_distance_ignore_ascii_case)
+    /// from this string to the target.
+    fn levenshtein_ignore_ascii_case(&self, target: &str) -> usize;
+
+    /// Returns the [local_levenshtein_distance](crate::local_levenshtein_distance) from this
+    /// string to the target, where this string is the search term.
+    fn local_levenshtein(&self, target: &str) -> usize;
+
+    /// Returns the [similarity_ratio](crate::similarity_ratio) of this string and the target.
+    fn similarity_ratio(&self, target: &str) -> f64;
+}
+
+impl FuzzyStr for str {
+    fn levenshtein(&self, target: &str) -> usize {
+        crate::levenshtein_distance(self, target)
+    }
+
+    fn levenshtein_ignore_ascii_case(&self, target: &str) -> usize {
+        crate::levenshtein_distance_ignore_ascii_case(self, target)
+    }
+
+    fn local_levenshtein(&self, target: &str) -> usize {
+        crate::local_levenshtein_distance(self, target)
+    }
+
+    fn similarity_ratio(&self, target: &str) -> f64 {
+        crate::similarity_ratio(self, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_as_free_functions() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("Rust", "rusty"),
+            ("", "bug"),
+            ("café", "cafe"),
+        ];
+        for (source, target) in pairs {
+            assert_eq!(
+                source.levenshtein(target),
+                crate::levenshtein_distance(source, target)
+            );
+            assert_eq!(
+                source.levenshtein_ignore_ascii_case(target),
+                crate::levenshtein_distance_ignore_ascii_case(source, target)
+            );
+            assert_eq!(
+                source.local_levenshtein(target),
+                crate::local_levenshtein_distance(source, target)
+            );
+            assert_eq!(
+                source.similarity_ratio(target),
+                crate::similarity_ratio(source, target)
+            );
+        }
+    }
+
+    #[test]
+    fn owned_strings() {
+        let source = String::from("rust");
+        let target = String::from("trust");
+        assert_eq!(source.levenshtein(&target), 1);
+    }
+}
@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::levenshtein_distance_slice;
+
+/// Returns the minimum number of single grapheme cluster insertions, deletions or substitutions
+/// required to convert the source string to the target string.
+///
+/// A grapheme cluster is closer to what a user thinks of as a single 'character' than a unicode
+/// scalar value is. For instance an emoji made of several emoji joined together with zero
+/// width joiners is one grapheme cluster, but several unicode scalar values, so
+/// [levenshtein_distance](crate::levenshtein_distance) counts replacing it as several edits.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, levenshtein_distance_graphemes};
+/// // The scientist emoji is the person emoji, a zero width joiner, and the microscope emoji
+/// assert_eq!(2, levenshtein_distance("🧑‍🔬", "🧑"));
+/// assert_eq!(1, levenshtein_distance_graphemes("🧑‍🔬", "🧑"));
+/// ```
+///
+/// Both strings are segmented into their extended grapheme clusters with
+/// [unicode-segmentation](https://docs.rs/unicode-segmentation) before comparing, and the
+/// clusters are compared for exact equality. Segmenting has to look up the grapheme break
+/// property of every character in the Unicode tables, so this is noticeably slower than
+/// [levenshtein_distance](crate::levenshtein_distance), and it allocates a list of clusters for
+/// each input. Prefer [levenshtein_distance](crate::levenshtein_distance) when your inputs
+/// don't contain combining marks or joined emoji.
+///
+/// This is only available with the `unicode-segmentation` feature enabled.
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+/// - [Unicode text segmentation](https://www.unicode.org/reports/tr29/)
+pub fn levenshtein_distance_graphemes(source: &str, target: &str) -> usize {
+    let source = source.graphemes(true).collect::<Vec<&str>>();
+    let target = target.graphemes(true).collect::<Vec<&str>>();
+    levenshtein_distance_slice(&source, &target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joined_emoji() {
+        let result = levenshtein_distance_graphemes("🧑‍🔬", "🧑");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_graphemes("🧑‍🔬 and 🧑‍🍳", "🧑 and 🧑");
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn combining_marks() {
+        // e followed by a combining acute accent is one grapheme cluster
+        let result = levenshtein_distance_graphemes("cafe\u{301}", "cafe");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_graphemes("cafe\u{301}", "cafe\u{301}");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn same_as_chars_for_ascii() {
+        for (source, target) in [("kitten", "sitting"), ("", "rust"), ("bug", "")] {
+            let result = levenshtein_distance_graphemes(source, target);
+            assert_eq!(result, crate::levenshtein_distance(source, target));
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Returns the Jaro similarity between the source and target strings, a score between 0.0 and
+/// 1.0 where 1.0 means the strings are identical and 0.0 means they have no matching characters.
+///
+/// Jaro similarity counts the characters the strings have in common within a small window of
+/// each other, and how many of those are out of order. Unlike Levenshtein distance, characters
+/// that are swapped around are only lightly penalised, which makes this well suited to short
+/// strings such as names.
+///
+/// ```
+/// use fuzzy_string_distance::jaro_similarity;
+/// let similarity = jaro_similarity("MARTHA", "MARHTA");
+/// assert!((similarity - 0.944).abs() < 0.001);
+/// ```
+///
+/// Characters are considered matching if they are equal and no further apart than half the
+/// length of the longer string minus one. The similarity is then
+/// `(m / source length + m / target length + (m - t) / m) / 3` where `m` is the number of
+/// matching characters and `t` is half the number of matching characters which are not in the
+/// same order in both strings.
+///
+/// See also:
+/// - [Jaro similarity](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance#Jaro_similarity)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn jaro_similarity(source: &str, target: &str) -> f64 {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    if source.is_empty() && target.is_empty() {
+        return 1.0;
+    }
+    if source.is_empty() || target.is_empty() {
+        return 0.0;
+    }
+
+    // Characters only match if they're within this many positions of each other
+    let window = (core::cmp::max(source.len(), target.len()) / 2).saturating_sub(1);
+
+    // Find the matching characters, each character in the target can only be matched once
+    let mut source_matched = vec![false; source.len()];
+    let mut target_matched = vec![false; target.len()];
+    let mut matches = 0;
+    for (i, source_char) in source.iter().enumerate() {
+        let first = i.saturating_sub(window);
+        let last = core::cmp::min(i + window + 1, target.len());
+        for j in first..last {
+            if !target_matched[j] && target[j] == *source_char {
+                source_matched[i] = true;
+                target_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // Walk through the matching characters of both strings in order, counting how many pairs
+    // disagree. Each swapped pair of characters will be counted twice, once for each position.
+    let source_matches = source
+        .iter()
+        .zip(source_matched)
+        .filter(|(_, matched)| *matched)
+        .map(|(c, _)| c);
+    let target_matches = target
+        .iter()
+        .zip(target_matched)
+        .filter(|(_, matched)| *matched)
+        .map(|(c, _)| c);
+    let half_transpositions = source_matches
+        .zip(target_matches)
+        .filter(|(s, t)| s != t)
+        .count();
+
+    let matches = matches as f64;
+    let transpositions = half_transpositions as f64 / 2.0;
+    (matches / source.len() as f64
+        + matches / target.len() as f64
+        + (matches - transpositions) / matches)
+        / 3.0
+}
+
+/// Returns the Jaro-Winkler similarity between the source and target strings, a score between
+/// 0.0 and 1.0 where 1.0 means the strings are identical and 0.0 means they have no matching
+/// characters.
+///
+/// This is the [jaro_similarity] with a bonus for strings that start with the same characters,
+/// which tends to work well for matching names. This uses the standard scaling factor of 0.1
+/// for each character in the common prefix, up to a maximum of 4 characters, see
+/// [jaro_winkler_similarity_with] to change these.
+///
+/// ```
+/// use fuzzy_string_distance::{jaro_similarity, jaro_winkler_similarity};
+/// let similarity = jaro_winkler_similarity("DWAYNE", "DUANE");
+/// assert!((similarity - 0.84).abs() < 0.001);
+/// assert!(similarity > jaro_similarity("DWAYNE", "DUANE"));
+/// ```
+///
+/// The bonus is only applied when the Jaro similarity is above 0.7, so strings which are not
+/// already fairly similar don't get boosted just for sharing a first letter.
+///
+/// See also:
+/// - [Jaro-Winkler similarity](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn jaro_winkler_similarity(source: &str, target: &str) -> f64 {
+    jaro_winkler_similarity_with(source, target, 0.1, 4)
+}
+
+/// Returns the Jaro-Winkler similarity between the source and target strings using the given
+/// scaling factor for each character in the common prefix, and the given maximum number of
+/// prefix characters to count.
+///
+/// The similarity is `j + l * prefix_weight * (1 - j)`, where `j` is the [jaro_similarity] and
+/// `l` is the number of characters the strings have in common at the start, up to
+/// `max_prefix`. As with [jaro_winkler_similarity], the bonus is only applied when the Jaro
+/// similarity is above 0.7. `prefix_weight * max_prefix` should not be greater than 1.0,
+/// otherwise the similarity could be greater than 1.0.
+///
+/// ```
+/// use fuzzy_string_distance::{jaro_similarity, jaro_winkler_similarity_with};
+/// // A weight of 0 removes the prefix bonus entirely
+/// assert_eq!(
+///     jaro_similarity("DWAYNE", "DUANE"),
+///     jaro_winkler_similarity_with("DWAYNE", "DUANE", 0.0, 4),
+/// );
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn jaro_winkler_similarity_with(
+    source: &str,
+    target: &str,
+    prefix_weight: f64,
+    max_prefix: usize,
+) -> f64 {
+    let jaro = jaro_similarity(source, target);
+    if jaro <= 0.7 {
+        return jaro;
+    }
+    let prefix = source
+        .chars()
+        .zip(target.chars())
+        .take(max_prefix)
+        .take_while(|(s, t)| s == t)
+        .count();
+    jaro + prefix as f64 * prefix_weight * (1.0 - jaro)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(left: f64, right: f64) {
+        assert!(
+            (left - right).abs() < 0.001,
+            "{} is not close to {}",
+            left,
+            right
+        );
+    }
+
+    #[test]
+    fn transposed_names() {
+        assert_close(jaro_similarity("MARTHA", "MARHTA"), 0.944);
+        assert_close(jaro_similarity("DIXON", "DICKSONX"), 0.767);
+        assert_close(jaro_similarity("DWAYNE", "DUANE"), 0.822);
+    }
+
+    #[test]
+    fn identical_and_unrelated() {
+        assert_eq!(jaro_similarity("rust", "rust"), 1.0);
+        assert_eq!(jaro_similarity("", ""), 1.0);
+        assert_eq!(jaro_similarity("abc", "xyz"), 0.0);
+        assert_eq!(jaro_similarity("bug", ""), 0.0);
+    }
+
+    #[test]
+    fn symmetric() {
+        assert_close(
+            jaro_similarity("El delfín español", "Dolphin"),
+            jaro_similarity("Dolphin", "El delfín español"),
+        );
+    }
+
+    #[test]
+    fn prefix_bonus() {
+        assert_close(jaro_winkler_similarity("MARTHA", "MARHTA"), 0.961);
+        assert_close(jaro_winkler_similarity("DIXON", "DICKSONX"), 0.813);
+        assert_close(jaro_winkler_similarity("DWAYNE", "DUANE"), 0.84);
+    }
+
+    #[test]
+    fn no_bonus_below_threshold() {
+        // Share a first letter but are otherwise quite different
+        let jaro = jaro_similarity("abcdef", "azzzzz");
+        assert!(jaro <= 0.7);
+        assert_eq!(jaro_winkler_similarity("abcdef", "azzzzz"), jaro);
+    }
+
+    #[test]
+    fn custom_prefix() {
+        // Counting more of the prefix gives a bigger bonus
+        let four = jaro_winkler_similarity_with("prefixes", "prefixed", 0.1, 4);
+        let six = jaro_winkler_similarity_with("prefixes", "prefixed", 0.1, 6);
+        assert!(six > four);
+        assert_eq!(jaro_winkler_similarity_with("rust", "rust", 0.25, 4), 1.0);
+    }
+}
@@ -0,0 +1,225 @@
+use alloc::vec::Vec;
+
+use crate::weighted::{levenshtein_distance_with, CostMatrix};
+
+/// A physical keyboard layout, for use with [keyboard_levenshtein_distance].
+///
+/// Only the main block of character keys is modelled, as four rows which are each offset from
+/// the row above by about half a key, as on most physical keyboards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyboardLayout {
+    /// The QWERTY layout used for English and many other languages
+    Qwerty,
+    /// The AZERTY layout used for French
+    Azerty,
+}
+
+impl KeyboardLayout {
+    /// The characters of each row of keys from top to bottom, as typed without shift.
+    fn rows(self) -> [&'static str; 4] {
+        match self {
+            KeyboardLayout::Qwerty => ["1234567890-=", "qwertyuiop[]", "asdfghjkl;'", "zxcvbnm,./"],
+            KeyboardLayout::Azerty => [
+                "&é\"'(-è_çà)=",
+                "azertyuiop^$",
+                "qsdfghjklmù*",
+                "wxcvbn,;:!",
+            ],
+        }
+    }
+
+    /// Returns a [CostMatrix] of the cost of substituting each character on the layout for
+    /// another, as used by [keyboard_levenshtein_distance].
+    ///
+    /// Substituting a character for one on the same key or a neighbouring key costs 1, and
+    /// substituting it for any other character costs 2. Both the lowercase and uppercase forms
+    /// of each key are included.
+    ///
+    /// ```
+    /// use fuzzy_string_distance::KeyboardLayout;
+    /// let matrix = KeyboardLayout::Qwerty.cost_matrix();
+    /// assert_eq!(1, matrix.cost('k', 'L'));
+    /// assert_eq!(2, matrix.cost('x', 'l'));
+    /// ```
+    pub fn cost_matrix(self) -> CostMatrix {
+        let keys = self.keys().collect::<Vec<(char, (usize, usize))>>();
+        let mut matrix = CostMatrix::new(2);
+        for &(a, a_position) in &keys {
+            for &(b, b_position) in &keys {
+                if a_position == b_position || neighbours(a_position, b_position) {
+                    for a in [a, uppercase(a)] {
+                        for b in [b, uppercase(b)] {
+                            matrix.insert(a, b, 1);
+                        }
+                    }
+                }
+            }
+        }
+        matrix
+    }
+
+    /// Returns the cost of substituting the source character for the target character on this
+    /// layout, the same as looking it up in the [cost_matrix](KeyboardLayout::cost_matrix).
+    fn cost(self, source: char, target: char) -> usize {
+        if source == target {
+            return 0;
+        }
+        match (self.position(source), self.position(target)) {
+            (Some(a), Some(b)) if a == b || neighbours(a, b) => 1,
+            _ => 2,
+        }
+    }
+
+    /// Returns the row and column of the key which types the character, either as is or with
+    /// shift, if it's on the layout.
+    fn position(self, character: char) -> Option<(usize, usize)> {
+        self.keys()
+            .find(|&(key, _)| key == character || uppercase(key) == character)
+            .map(|(_, position)| position)
+    }
+
+    /// Returns each character on the layout as typed without shift, with its row and column.
+    fn keys(self) -> impl Iterator<Item = (char, (usize, usize))> {
+        self.rows().into_iter().enumerate().flat_map(|(row, keys)| {
+            keys.chars()
+                .enumerate()
+                .map(move |(column, key)| (key, (row, column)))
+        })
+    }
+}
+
+/// Returns the minimum total cost of single character insertions, deletions or substitutions
+/// required to convert the source string to the target string, where substituting a character
+/// for one on a neighbouring key of the keyboard layout costs less than substituting it for a
+/// character on a distant key.
+///
+/// This models typos more closely than [levenshtein_distance](crate::levenshtein_distance), as
+/// hitting the key next to the intended one is a much more likely mistake than hitting a key on
+/// the other side of the keyboard. Insertions, deletions and substitutions between neighbouring
+/// keys cost 1, while substitutions between keys which aren't neighbours cost 2, the same as
+/// deleting the character and inserting the other.
+///
+/// ```
+/// use fuzzy_string_distance::{keyboard_levenshtein_distance, KeyboardLayout};
+/// // k is next to l, but x and y are far from it
+/// assert_eq!(2, keyboard_levenshtein_distance("hekko", "hello", KeyboardLayout::Qwerty));
+/// assert_eq!(4, keyboard_levenshtein_distance("hexyo", "hello", KeyboardLayout::Qwerty));
+/// ```
+///
+/// Keys are neighbours if they are next to each other on the same row, or touching on the rows
+/// above or below. A character and its uppercase form are on the same key, so substituting one
+/// for the other also costs 1. Characters which aren't on the layout have no neighbours. This
+/// is the same as [levenshtein_distance_with_matrix](crate::levenshtein_distance_with_matrix)
+/// with the layout's [cost_matrix](KeyboardLayout::cost_matrix).
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+/// - [QWERTY](https://en.wikipedia.org/wiki/QWERTY)
+/// - [AZERTY](https://en.wikipedia.org/wiki/AZERTY)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn keyboard_levenshtein_distance(source: &str, target: &str, layout: KeyboardLayout) -> usize {
+    levenshtein_distance_with(source, target, |a, b| layout.cost(a, b))
+}
+
+/// Returns true if the keys at the two rows and columns are touching.
+fn neighbours((a_row, a_column): (usize, usize), (b_row, b_column): (usize, usize)) -> bool {
+    // Each row is offset to the right of the row above by about half a key, so a key touches
+    // the key at the same column and the one after it in the row above, and the key at the
+    // same column and the one before it in the row below
+    if a_row == b_row {
+        a_column.abs_diff(b_column) == 1
+    } else if a_row + 1 == b_row {
+        b_column == a_column || b_column + 1 == a_column
+    } else if b_row + 1 == a_row {
+        a_column == b_column || a_column + 1 == b_column
+    } else {
+        false
+    }
+}
+
+/// Returns the uppercase form of the character if it uppercases to a single character.
+fn uppercase(c: char) -> char {
+    let mut uppercase = c.to_uppercase();
+    match (uppercase.next(), uppercase.next()) {
+        (Some(upper), None) => upper,
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacent_keys_are_cheaper() {
+        let near = keyboard_levenshtein_distance("hekko", "hello", KeyboardLayout::Qwerty);
+        let far = keyboard_levenshtein_distance("hexyo", "hello", KeyboardLayout::Qwerty);
+        assert_eq!(near, 2);
+        assert_eq!(far, 4);
+        assert!(near < far);
+    }
+
+    #[test]
+    fn neighbouring_rows() {
+        let matrix = KeyboardLayout::Qwerty.cost_matrix();
+        // s touches w and e above it and z and x below it
+        for key in ['a', 'd', 'w', 'e', 'z', 'x', 'S', 'W'] {
+            assert_eq!(matrix.cost('s', key), 1, "{key}");
+        }
+        for key in ['q', 'r', 'c', 'f', '1', 'ß'] {
+            assert_eq!(matrix.cost('s', key), 2, "{key}");
+        }
+    }
+
+    #[test]
+    fn azerty() {
+        // On AZERTY a is where q is on QWERTY, so a and z are neighbours but a and s are not
+        let result = keyboard_levenshtein_distance("a", "z", KeyboardLayout::Azerty);
+        assert_eq!(result, 1);
+        let result = keyboard_levenshtein_distance("a", "s", KeyboardLayout::Azerty);
+        assert_eq!(result, 2);
+        let result = keyboard_levenshtein_distance("a", "s", KeyboardLayout::Qwerty);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn azerty_bottom_row() {
+        // w is under q and s, and x is under s and d, as on QWERTY z and x
+        let matrix = KeyboardLayout::Azerty.cost_matrix();
+        assert_eq!(matrix.cost('w', 'q'), 1);
+        assert_eq!(matrix.cost('w', 's'), 1);
+        assert_eq!(matrix.cost('x', 's'), 1);
+        assert_eq!(matrix.cost('x', 'd'), 1);
+        assert_eq!(matrix.cost('w', 'd'), 2);
+        assert_eq!(matrix.cost('x', 'f'), 2);
+    }
+
+    #[test]
+    fn same_costs_as_matrix() {
+        for layout in [KeyboardLayout::Qwerty, KeyboardLayout::Azerty] {
+            let matrix = layout.cost_matrix();
+            let characters = layout
+                .keys()
+                .flat_map(|(key, _)| [key, uppercase(key)])
+                .chain(['ß', ' ', '€'])
+                .collect::<Vec<char>>();
+            for &a in &characters {
+                for &b in &characters {
+                    assert_eq!(layout.cost(a, b), matrix.cost(a, b), "{a} {b}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn same_length_as_levenshtein() {
+        let result = keyboard_levenshtein_distance("", "rust", KeyboardLayout::Qwerty);
+        assert_eq!(result, 4);
+        let result = keyboard_levenshtein_distance("RUST", "rust", KeyboardLayout::Qwerty);
+        assert_eq!(result, 4);
+    }
+}
@@ -0,0 +1,114 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Returns the length of the longest common subsequence of the source and target strings, which
+/// is the greatest number of characters that appear in both strings in the same order, though
+/// not necessarily next to each other.
+///
+/// ```
+/// use fuzzy_string_distance::longest_common_subsequence;
+/// assert_eq!(4, longest_common_subsequence("ABCBDAB", "BDCAB")); // BCAB
+/// assert_eq!(0, longest_common_subsequence("abc", "xyz"));
+/// ```
+///
+/// See also:
+/// - [Longest common subsequence](https://en.wikipedia.org/wiki/Longest_common_subsequence)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn longest_common_subsequence(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let target_chars = target.len();
+
+    // We'll have a matrix A of `source` length + 1 rows and `target` length + 1 columns
+    // This stores the longest common subsequence for prefixes of source and target from the
+    // empty string through to the entire inputs.
+    // As with Levenshtein distance, we only need to store two rows at a time. The first row
+    // and first column are all 0, as nothing is in common with an empty string.
+    let mut lengths = vec![0; target_chars + 1];
+    let mut new_lengths = vec![0; target_chars + 1];
+
+    for source_char in &source {
+        for (j, target_char) in target.iter().enumerate() {
+            // We're calculating A[i + 1, j + 1]
+            new_lengths[j + 1] = if source_char == target_char {
+                // If the characters match, we can extend the subsequence of both prefixes
+                // without these characters at A[i, j]
+                lengths[j] + 1
+            } else {
+                // Otherwise, the best we can do is the longer subsequence of either ignoring
+                // this source character at A[i, j + 1] or ignoring this target character
+                // at A[i + 1, j]
+                core::cmp::max(lengths[j + 1], new_lengths[j])
+            };
+        }
+
+        core::mem::swap(&mut lengths, &mut new_lengths);
+    }
+
+    lengths[target_chars]
+}
+
+/// Returns the minimum number of single character insertions or deletions required to convert
+/// the source string to the target string, known as the indel distance.
+///
+/// Unlike [levenshtein_distance](crate::levenshtein_distance), substitutions are not allowed, so
+/// replacing one character with another costs 2 edits, deleting the old character and inserting
+/// the new one. Every character not in the [longest_common_subsequence] needs to be deleted from
+/// the source or inserted into the target, so this is calculated as
+/// `source length + target length - 2 * longest common subsequence`.
+///
+/// ```
+/// use fuzzy_string_distance::{indel_distance, levenshtein_distance};
+/// assert_eq!(2, indel_distance("abc", "axc")); // delete b and insert x
+/// assert_eq!(1, levenshtein_distance("abc", "axc")); // substitute b for x
+/// ```
+///
+/// See also:
+/// - [Edit distance](https://en.wikipedia.org/wiki/Edit_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn indel_distance(source: &str, target: &str) -> usize {
+    let common = longest_common_subsequence(source, target);
+    source.chars().count() + target.chars().count() - 2 * common
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn common_subsequence() {
+        let result = longest_common_subsequence("ABCBDAB", "BDCAB");
+        assert_eq!(result, 4);
+        let result = longest_common_subsequence("kitten", "sitting");
+        // ittn
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn empty_subsequence() {
+        let result = longest_common_subsequence("", "rust");
+        assert_eq!(result, 0);
+        let result = longest_common_subsequence("bug", "");
+        assert_eq!(result, 0);
+        let result = longest_common_subsequence("abc", "xyz");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn indel() {
+        let result = indel_distance("abc", "axc");
+        assert_eq!(result, 2);
+        let result = indel_distance("kitten", "sitting");
+        assert_eq!(result, 5);
+        let result = indel_distance("", "rust");
+        assert_eq!(result, 4);
+        let result = indel_distance("rust", "rust");
+        assert_eq!(result, 0);
+    }
+}
@@ -9,6 +9,128 @@
 //! assert_eq!(2, levenshtein_distance(&"typography", &"typpgrapy")); // fix both typos
 //! ```
 //!
+//! The crate is `no_std` compatible by disabling the default `std` feature, in which case it only
+//! depends on `alloc`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod alignment;
+mod bk_tree;
+mod case;
+mod clustering;
+mod comparator;
+mod damerau;
+mod dictionary;
+mod fuzzy_eq;
+mod fuzzy_iter;
+mod fuzzy_str;
+#[cfg(feature = "unicode-segmentation")]
+mod graphemes;
+mod jaro;
+mod keyboard;
+mod lcs;
+mod local;
+mod locale;
+mod myers;
+mod ngram;
+#[cfg(feature = "unicode-normalization")]
+mod normalization;
+mod ocr;
+mod ops;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod phonetic;
+mod preprocessing;
+#[cfg(test)]
+mod properties;
+mod query;
+mod search;
+#[cfg(feature = "simd")]
+mod simd;
+mod similarity;
+#[cfg(feature = "unicode-security")]
+mod skeleton;
+#[cfg(feature = "tinyvec")]
+mod small;
+mod streaming;
+mod tokens;
+mod trigram;
+mod weighted;
+mod wildcard;
+#[cfg(feature = "unicode-segmentation")]
+mod words;
+
+pub use alignment::{needleman_wunsch, smith_waterman, Scoring};
+pub use bk_tree::BkTree;
+pub use clustering::{cluster_by_threshold, dedup_fuzzy, distance_matrix};
+pub use comparator::{Comparator, ComparatorBuilder};
+pub use damerau::{
+    damerau_levenshtein_distance, damerau_levenshtein_distance_unrestricted, osa_distance_weighted,
+    OsaCosts,
+};
+pub use dictionary::Dictionary;
+pub use fuzzy_eq::FuzzyEq;
+pub use fuzzy_iter::FuzzyIterator;
+pub use fuzzy_str::FuzzyStr;
+#[cfg(feature = "unicode-segmentation")]
+pub use graphemes::levenshtein_distance_graphemes;
+pub use jaro::{jaro_similarity, jaro_winkler_similarity, jaro_winkler_similarity_with};
+pub use keyboard::{keyboard_levenshtein_distance, KeyboardLayout};
+pub use lcs::{indel_distance, longest_common_subsequence};
+pub use local::{
+    fuzzy_contains, fuzzy_find_all, local_levenshtein_match, local_levenshtein_ops,
+    prefix_levenshtein_distance, suffix_levenshtein_distance, LocalMatch,
+};
+pub use locale::{levenshtein_distance_ignore_case_locale, Locale};
+pub use myers::levenshtein_distance_myers;
+pub use ngram::{dice_coefficient, ngram_jaccard_similarity};
+#[cfg(feature = "unicode-normalization")]
+pub use normalization::{
+    levenshtein_distance_ignore_diacritics, levenshtein_distance_normalized, Normalization,
+};
+pub use ocr::{levenshtein_distance_confusable, levenshtein_distance_ocr, ConfusionMap};
+pub use ops::{
+    align, apply_ops, edit_counts, levenshtein_matrix, levenshtein_ops, levenshtein_ops_linear,
+    matching_characters, EditCounts, EditOp,
+};
+#[cfg(feature = "rayon")]
+pub use parallel::{distance_matrix_par, levenshtein_distances_par};
+pub use phonetic::{
+    metaphone, metaphone_matches, phonetic_similarity, phonetic_similarity_with, soundex,
+    soundex_matches, PhoneticWeights,
+};
+pub use preprocessing::{
+    levenshtein_distance_collapse_whitespace, levenshtein_distance_ignore_punctuation,
+    levenshtein_distance_ignoring, levenshtein_distance_preprocessed,
+    levenshtein_distance_squeeze_repeats,
+};
+pub use query::Query;
+pub use search::{
+    best_match, best_match_index, k_nearest, sort_by_distance, sorted_by_distance, suggest,
+    suggestions,
+};
+pub use similarity::{
+    combined_similarity, is_similar, is_similar_ratio, prefix_similarity, similarity_at_least,
+    similarity_percent, similarity_ratio, SimilarityWeights,
+};
+#[cfg(feature = "unicode-security")]
+pub use skeleton::levenshtein_distance_skeleton;
+pub use streaming::StreamingLevenshtein;
+pub use tokens::{token_levenshtein_distance, token_levenshtein_with};
+pub use trigram::TrigramIndex;
+pub use weighted::{
+    levenshtein_distance_prefix_weighted, levenshtein_distance_weighted, levenshtein_distance_with,
+    levenshtein_distance_with_classes, levenshtein_distance_with_matrix,
+    local_levenshtein_distance_weighted, CostMatrix, Costs,
+};
+pub use wildcard::levenshtein_distance_wildcard;
+#[cfg(feature = "unicode-segmentation")]
+pub use words::word_levenshtein_distance;
 
 /// Returns the minimum number of single character insertions, deletions or substitutions
 /// required to convert the source string to the target string, known as the Levenshtein distance.
@@ -16,6 +138,10 @@
 /// This is like a fuzzy [Eq], where a distance of 0 means the strings are equal
 /// and the distance can be up to the length of the longer string if they are completely unrelated.
 ///
+/// With the `tinyvec` feature enabled, the characters of each input and the row of edit
+/// distances are kept on the stack while they fit in 32 elements, so comparing short strings
+/// doesn't allocate at all. Longer inputs spill over onto the heap as usual.
+///
 /// See also:
 /// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
 ///
@@ -23,11 +149,383 @@
 /// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
 /// still does not compare grapheme clusters.
 pub fn levenshtein_distance(source: &str, target: &str) -> usize {
-    // If either input is empty then the shortest transformation is all deletions or insertions
-    // from/to an empty string, which will be equal to the number of characters in the other input
-    // This check also guards against any index out of bounds issues in the main implementation
-    let target_chars = target.chars().count();
-    let source_chars = source.chars().count();
+    #[cfg(feature = "tinyvec")]
+    return small::levenshtein_distance_small(source, target);
+    #[cfg(not(feature = "tinyvec"))]
+    Levenshtein::new().distance(source, target)
+}
+
+/// Reusable buffers for computing the [levenshtein_distance] between many pairs of strings.
+///
+/// Each call to [levenshtein_distance] has to allocate space to store the characters of its
+/// inputs and the row of edit distances it works through, unless the inputs are short and the
+/// `tinyvec` feature is enabled. When comparing one query against
+/// thousands of targets in a loop, reusing a single `Levenshtein` avoids allocating on every
+/// comparison, as the buffers are only grown when a longer input than before comes along.
+///
+/// ```
+/// use fuzzy_string_distance::Levenshtein;
+/// let mut levenshtein = Levenshtein::new();
+/// let words = ["rusty", "crust", "trust", "dust"];
+/// let distances = words
+///     .iter()
+///     .map(|word| levenshtein.distance("rust", word))
+///     .collect::<Vec<usize>>();
+/// assert_eq!(vec![1, 1, 1, 1], distances);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Levenshtein {
+    source: Vec<char>,
+    target: Vec<char>,
+    edit_distances: Vec<usize>,
+}
+
+impl Levenshtein {
+    /// Creates a new Levenshtein with empty buffers, which will grow as needed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the minimum number of single character insertions, deletions or substitutions
+    /// required to convert the source string to the target string, known as the Levenshtein
+    /// distance.
+    ///
+    /// This returns the same result as [levenshtein_distance] but reuses the buffers from any
+    /// previous calls.
+    pub fn distance(&mut self, source: &str, target: &str) -> usize {
+        // Collect the characters of both inputs once up front so we can index into them in the
+        // main loop without re-walking the UTF-8 strings
+        self.source.clear();
+        self.source.extend(source.chars());
+        self.target.clear();
+        self.target.extend(target.chars());
+        levenshtein_distance_buffered(&self.source, &self.target, &mut self.edit_distances)
+    }
+}
+
+/// Returns the minimum number of single element insertions, deletions or substitutions
+/// required to convert the source slice to the target slice, known as the Levenshtein distance.
+///
+/// This is the same as [levenshtein_distance] but works over any slice of elements that can be
+/// compared for equality, rather than only the characters of a string. This can be used to
+/// compare sequences of tokens or words instead of characters.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_slice;
+/// let source = ["the", "quick", "brown", "fox"];
+/// let target = ["the", "slow", "brown", "fox"];
+/// assert_eq!(1, levenshtein_distance_slice(&source, &target)); // substitute quick for slow
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+pub fn levenshtein_distance_slice<T: PartialEq>(source: &[T], target: &[T]) -> usize {
+    #[cfg(feature = "tinyvec")]
+    return small::levenshtein_distance_slice_small(source, target);
+    #[cfg(not(feature = "tinyvec"))]
+    levenshtein_distance_buffered(source, target, &mut Vec::new())
+}
+
+/// Returns the minimum number of single element insertions, deletions or substitutions
+/// required to convert the source slice to the target slice, where two elements are the same if
+/// `eq` returns true for them.
+///
+/// This is the same as [levenshtein_distance_slice], but compares elements with a custom
+/// equality function instead of [PartialEq]. This can be used to compare elements that don't
+/// implement PartialEq, or that should be compared differently, such as by only some of their
+/// fields or ignoring case.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_slice_by;
+/// let source = ["The", "Quick", "brown", "fox"];
+/// let target = ["the", "slow", "Brown", "fox"];
+/// let ignore_case = |a: &&str, b: &&str| a.eq_ignore_ascii_case(b);
+/// assert_eq!(1, levenshtein_distance_slice_by(&source, &target, ignore_case));
+/// ```
+///
+/// `eq` is always called with an element from the source first and an element from the target
+/// second, and should return true when an element is compared with itself.
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+pub fn levenshtein_distance_slice_by<T, F>(source: &[T], target: &[T], eq: F) -> usize
+where
+    F: Fn(&T, &T) -> bool,
+{
+    #[cfg(feature = "tinyvec")]
+    return small::levenshtein_distance_slice_small_by(source, target, eq);
+    #[cfg(not(feature = "tinyvec"))]
+    levenshtein_distance_buffered_by(source, target, &mut Vec::new(), eq)
+}
+
+/// Returns the minimum number of single byte insertions, deletions or substitutions required
+/// to convert the source bytes to the target bytes.
+///
+/// This is a fast path for [levenshtein_distance] when the inputs are known to be ASCII, such
+/// as identifiers or log lines. It runs directly over the bytes, so it skips decoding the
+/// strings into characters and stores one byte per element instead of four.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_ascii;
+/// assert_eq!(1, levenshtein_distance_ascii(b"rust", b"rusty"));
+/// assert_eq!(3, levenshtein_distance_ascii("kitten".as_bytes(), "sitting".as_bytes()));
+/// ```
+///
+/// Each byte is treated as a unit, so characters that take more than one byte in UTF-8 give
+/// the wrong result. Only use this when you know your inputs are ASCII, otherwise use
+/// [levenshtein_distance].
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, levenshtein_distance_ascii};
+/// // é is two bytes in UTF-8, so substituting it counts as two edits
+/// assert_eq!(1, levenshtein_distance("cafe", "café"));
+/// assert_eq!(2, levenshtein_distance_ascii(b"cafe", "café".as_bytes()));
+/// ```
+///
+/// With the `simd` feature enabled, this calculates the matrix of edit distances one
+/// anti-diagonal at a time instead of one row at a time. The entries along an anti-diagonal
+/// don't depend on each other, so the compiler can calculate many of them at once with SIMD
+/// instructions, which is many times faster for long inputs. Inputs of 65535 bytes or more
+/// fall back to the scalar implementation, as the vectorized one stores distances in 16 bits.
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+pub fn levenshtein_distance_ascii(source: &[u8], target: &[u8]) -> usize {
+    #[cfg(feature = "simd")]
+    if let Some(distance) = simd::levenshtein_distance_anti_diagonal(source, target) {
+        return distance;
+    }
+    levenshtein_distance_slice(source, target)
+}
+
+/// Returns the minimum number of single character insertions, deletions or substitutions
+/// required to convert the source bytes to the target bytes, where the bytes are mostly UTF-8
+/// but may contain invalid sequences.
+///
+/// This is for data such as logs, which are meant to be UTF-8 but occasionally have stray
+/// bytes that would otherwise need a lossy conversion before comparing. The valid UTF-8 in each
+/// input is decoded into characters, and each byte that isn't part of a valid UTF-8 sequence is
+/// treated as a unit of its own, which is never equal to any character.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_bytes;
+/// assert_eq!(1, levenshtein_distance_bytes(b"error: disk full", b"error: disk full\xFF"));
+/// assert_eq!(1, levenshtein_distance_bytes(b"caf\xFF", "café".as_bytes()));
+/// ```
+///
+/// For inputs which are entirely valid UTF-8, including all ASCII inputs, this returns the
+/// same result as [levenshtein_distance] on the strings. Unlike [levenshtein_distance_ascii], a
+/// character which takes more than one byte in UTF-8 still counts as a single character.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, levenshtein_distance_bytes};
+/// assert_eq!(
+///     levenshtein_distance("El delfín", "Dolphin"),
+///     levenshtein_distance_bytes("El delfín".as_bytes(), b"Dolphin"),
+/// );
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares valid UTF-8 on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_bytes(source: &[u8], target: &[u8]) -> usize {
+    /// A character decoded from valid UTF-8, or a byte that isn't part of any valid sequence.
+    #[derive(PartialEq)]
+    enum Unit {
+        Char(char),
+        Byte(u8),
+    }
+    let decode = |bytes: &[u8]| {
+        let mut units = Vec::with_capacity(bytes.len());
+        for chunk in bytes.utf8_chunks() {
+            units.extend(chunk.valid().chars().map(Unit::Char));
+            units.extend(chunk.invalid().iter().copied().map(Unit::Byte));
+        }
+        units
+    };
+    levenshtein_distance_slice(&decode(source), &decode(target))
+}
+
+/// Returns the minimum number of single character insertions, deletions or substitutions
+/// required to convert the source string to the target string, capped at 255.
+///
+/// This returns the same result as [levenshtein_distance] whenever the distance is less than
+/// 255, but stores the row of edit distances as bytes instead of `usize`, which takes an eighth
+/// of the memory on 64 bit targets. This is useful on memory constrained targets, or when
+/// storing distances in bulk where they never need to be large.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_u8;
+/// assert_eq!(3, levenshtein_distance_u8("kitten", "sitting"));
+/// let long = "a".repeat(300);
+/// assert_eq!(255, levenshtein_distance_u8(&long, ""));
+/// ```
+///
+/// The distance saturates rather than wrapping around, so a result of 255 means the distance
+/// is **at least** 255, not exactly 255. Use [levenshtein_distance] if you need to tell
+/// distances of 255 or more apart.
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_u8(source: &str, target: &str) -> u8 {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    // As with levenshtein_distance we size the row by the shorter input
+    let (source, target) = if target.len() > source.len() {
+        (target, source)
+    } else {
+        (source, target)
+    };
+    // Every edit adds 1 and we only ever take minimums, so capping every entry at 255 gives the
+    // same result as capping the final distance, and saturating additions keep the entries
+    // capped without overflowing
+    let saturate = |distance: usize| u8::try_from(distance).unwrap_or(u8::MAX);
+    let mut edit_distances = (0..=target.len()).map(saturate).collect::<Vec<u8>>();
+    for (i, source_char) in source.iter().enumerate() {
+        let mut previous_diagonal = edit_distances[0];
+        edit_distances[0] = saturate(i + 1);
+        for (j, target_char) in target.iter().enumerate() {
+            let above = edit_distances[j + 1];
+            let substitution = if source_char == target_char {
+                previous_diagonal
+            } else {
+                previous_diagonal.saturating_add(1)
+            };
+            let deletion_or_insertion = core::cmp::min(above, edit_distances[j]).saturating_add(1);
+            edit_distances[j + 1] = core::cmp::min(substitution, deletion_or_insertion);
+            previous_diagonal = above;
+        }
+    }
+    edit_distances[target.len()]
+}
+
+/// Returns the minimum number of single character insertions, deletions or substitutions
+/// required to convert the source characters to the target characters, without needing either
+/// of them to be a [str].
+///
+/// This returns the same result as [levenshtein_distance], but takes any iterators of
+/// characters, such as characters decoded on the fly from another encoding or generated
+/// lazily, so you don't have to build a [String] first.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_iter;
+/// // Decode UTF-16 without collecting it into a String
+/// let source = [0x72, 0x75, 0x73, 0x74]; // rust
+/// let source = char::decode_utf16(source).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER));
+/// assert_eq!(1, levenshtein_distance_iter(source, "rusty".chars()));
+/// ```
+///
+/// The source is only iterated once, one character at a time, and is never stored. The target
+/// is collected into a buffer once up front, as it has to be read again for every character of
+/// the source, so it doesn't need to be [Clone]. Unlike [levenshtein_distance] this can't swap
+/// the inputs to keep the buffers sized by the shorter one, so pass the shorter input as the
+/// target where you can.
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares characters on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_iter<I, J>(source: I, target: J) -> usize
+where
+    I: IntoIterator<Item = char>,
+    J: IntoIterator<Item = char>,
+{
+    let target = target.into_iter().collect::<Vec<char>>();
+    // This is the same single row recurrence as levenshtein_distance, where each character of
+    // the source moves us down a row of the matrix. The row and its loop don't need to know how
+    // many rows there are, which is what lets us stream the source.
+    let mut edit_distances = (0..=target.len()).collect::<Vec<usize>>();
+    for (i, source_char) in source.into_iter().enumerate() {
+        let mut previous_diagonal = edit_distances[0];
+        edit_distances[0] = i + 1;
+        for (j, target_char) in target.iter().enumerate() {
+            let above = edit_distances[j + 1];
+            let substitution = if source_char == *target_char {
+                previous_diagonal
+            } else {
+                previous_diagonal + 1
+            };
+            edit_distances[j + 1] = core::cmp::min(
+                above + 1,
+                core::cmp::min(edit_distances[j] + 1, substitution),
+            );
+            previous_diagonal = above;
+        }
+    }
+    edit_distances[target.len()]
+}
+
+/// The implementation of Levenshtein distance over slices, which uses the provided buffer
+/// for the row of edit distances so callers can reuse it.
+fn levenshtein_distance_buffered<T: PartialEq>(
+    source: &[T],
+    target: &[T],
+    edit_distances: &mut Vec<usize>,
+) -> usize {
+    levenshtein_distance_buffered_by(source, target, edit_distances, |s, t| s == t)
+}
+
+/// The implementation of Levenshtein distance over slices with a custom equality function,
+/// which uses the provided buffer for the row of edit distances so callers can reuse it.
+pub(crate) fn levenshtein_distance_buffered_by<T, F>(
+    source: &[T],
+    target: &[T],
+    edit_distances: &mut Vec<usize>,
+    eq: F,
+) -> usize
+where
+    F: Fn(&T, &T) -> bool,
+{
+    edit_distances.resize(core::cmp::min(source.len(), target.len()) + 1, 0);
+    levenshtein_distance_in_row(source, target, edit_distances, eq)
+}
+
+/// The implementation of Levenshtein distance over slices with a custom equality function,
+/// which uses the provided row of edit distances. The row must have space for at least one
+/// more entry than the shorter input.
+pub(crate) fn levenshtein_distance_in_row<T, F>(
+    source: &[T],
+    target: &[T],
+    edit_distances: &mut [usize],
+    eq: F,
+) -> usize
+where
+    F: Fn(&T, &T) -> bool,
+{
+    // Levenshtein distance is symmetric, so we're free to swap the inputs around. The row of
+    // edit distances we store is sized by the `target`, so we make sure the shorter input is
+    // always the `target` to keep the buffer as small as possible regardless of the order the
+    // inputs were given in. We still call the equality function with elements from the source
+    // first in case it isn't symmetric.
+    if target.len() > source.len() {
+        levenshtein_rows(target, source, edit_distances, |t, s| eq(s, t))
+    } else {
+        levenshtein_rows(source, target, edit_distances, eq)
+    }
+}
+
+/// The main loop of Levenshtein distance, which needs a row of edit distances with space for
+/// at least one more entry than the target.
+fn levenshtein_rows<T, F>(source: &[T], target: &[T], edit_distances: &mut [usize], eq: F) -> usize
+where
+    F: Fn(&T, &T) -> bool,
+{
+    // If either input is empty then the shortest transformation is all deletions or
+    // insertions from/to an empty string, which will be equal to the number of characters
+    // in the other input
+    // This check also guards against any index out of bounds issues in the main
+    // implementation
+    let target_chars = target.len();
+    let source_chars = source.len();
     if source.is_empty() {
         return target_chars;
     }
@@ -38,69 +536,375 @@ pub fn levenshtein_distance(source: &str, target: &str) -> usize {
     // We'll have a matrix A of `source` length + 1 rows and `target` length + 1 columns
     // This stores the edit distances for prefixes of source and target from the empty string
     // through to the entire inputs.
-    // A[0, 0] is therefore "" to "" which is 0, and A[source length + 1, target length + 1] is
-    // the edit distance from source to target.
-    // We only need to store two rows at a time so we never construct this matrix.
+    // A[0, 0] is therefore "" to "" which is 0, and A[source length + 1, target length + 1]
+    // is the edit distance from source to target.
+    // Each entry only depends on the entry to its left, the entry above, and the entry
+    // diagonally above and to the left, so we only need to store a single row which we
+    // overwrite in place, carrying the diagonal entry along in a temporary as we go.
 
-    let mut edit_distances = vec![0; target_chars + 1];
-    // First row of edit distances are converting an empty string `source` to prefixes of length 0
-    // to the entire `target`, "" to "" is 0 edits, "" to one character is one insertion, and
-    // so on through to the entire target string.
-    for (i, x) in edit_distances.iter_mut().enumerate() {
-        *x = i;
+    // First row of edit distances are converting an empty string `source` to prefixes of
+    // length 0 to the entire `target`, "" to "" is 0 edits, "" to one character is one
+    // insertion, and so on through to the entire target string.
+    for (j, edit_distance) in edit_distances[..=target_chars].iter_mut().enumerate() {
+        *edit_distance = j;
     }
 
-    for i in 0..source_chars {
-        // Step through each subsequent row of the matrix of edit distances, each time looking at
-        // a prefix of `source` one character longer
-        let mut new_edit_distances = vec![0; target_chars + 1];
-        // We're on the i+1 prefix of characters in `source`, so converting this to an empty string
-        // (the 0 character prefix of target) is purely deletions equal to the length of the
-        // source.
-        new_edit_distances[0] = i + 1;
+    for (i, source_element) in source.iter().enumerate() {
+        // Step through each subsequent row of the matrix of edit distances, each time looking
+        // at a prefix of `source` one character longer
+        // Before we overwrite it, the first entry holds A[i, 0], which is diagonally above
+        // and to the left of the first entry we'll calculate in this row.
+        let mut previous_diagonal = edit_distances[0];
+        // We're on the i+1 prefix of characters in `source`, so converting this to an empty
+        // string (the 0 character prefix of target) is purely deletions equal to the length
+        // of the source.
+        edit_distances[0] = i + 1;
 
-        for j in 0..target_chars {
-            // Step through columns for the prefixes of `target` on this prefix of `source` row.
-            // For a source of "kitten" and a target of "sitting", if we were up to i = 1 and
-            // j = 2 then this would look like a source of "ki" we already have the distance for
-            // converting to "si" and we now need to work out the distance to convert to "sit".
-            // We're now calculating the edit distance for A[i + 1, j + 1]
+        for (j, target_element) in target.iter().enumerate() {
+            // Step through columns for the prefixes of `target` on this prefix of `source`
+            // row. For a source of "kitten" and a target of "sitting", if we were up to i = 1
+            // and j = 2 then this would look like a source of "ki" we already have the
+            // distance for converting to "si" and we now need to work out the distance to
+            // convert to "sit".
+            // We're now calculating the edit distance for A[i + 1, j + 1], entries from
+            // j + 1 onwards still hold the previous row and entries before j + 1 have
+            // already been overwritten with this row.
 
             // At A[i, j + 1] we have the cost to reach the same `target` prefix with a source
-            // that was one character shorter, so we can delete the extraneous character and the
-            // distance could be 1 greater
-            let deletion = edit_distances[j + 1] + 1;
-            // At A[i + 1, j] we have the cost to reach a shorter `target` prefix with the same
-            // source, so we can insert the extra character and the distance could be 1 greater
-            let insertion = new_edit_distances[j] + 1;
-            // We can unwrap here because we're taking an element from both iterators within
-            // their respective bounds of 0 to source_chars -1 and target_chars - 1
-            let source_char = source.chars().skip(i).next().unwrap();
-            let target_char = target.chars().skip(j).next().unwrap();
-            let substitution = if source_char == target_char {
+            // that was one character shorter, so we can delete the extraneous character and
+            // the distance could be 1 greater
+            let above = edit_distances[j + 1];
+            let deletion = above + 1;
+            // At A[i + 1, j] we have the cost to reach a shorter `target` prefix with the
+            // same source, so we can insert the extra character and the distance could be 1
+            // greater
+            let insertion = edit_distances[j] + 1;
+            // At A[i, j] which we carried over in `previous_diagonal` we have the cost to
+            // reach both prefixes one character shorter
+            let substitution = if eq(source_element, target_element) {
                 // If the `source` character at i and the `target` character at j match, we
                 // don't need to transform anything
-                edit_distances[j]
+                previous_diagonal
             } else {
-                // Otherwise we can transform the character to match the target, and the distance
-                // could be 1 greater
-                edit_distances[j] + 1
+                // Otherwise we can transform the character to match the target, and the
+                // distance could be 1 greater
+                previous_diagonal + 1
             };
 
             // We always pick the cheapest option from the 3 we could do, which populates
             // A[i + 1, j + 1]
-            new_edit_distances[j + 1] = std::cmp::min(
-                deletion, std::cmp::min(insertion, substitution)
-            );
+            edit_distances[j + 1] =
+                core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+            // A[i, j + 1] is diagonally above and to the left of the next entry
+            previous_diagonal = above;
         }
-
-        edit_distances = new_edit_distances;
     }
     // The distance from `target` to `source` will be the final entry in the array as this
     // is the full strings of both with no characters ignored.
     edit_distances[target_chars]
 }
 
+/// Returns the Levenshtein distance between the source and target strings if it is no greater
+/// than `max`, or `None` if the distance exceeds `max`.
+///
+/// This returns the same distance as [levenshtein_distance] whenever that distance is within the
+/// threshold, but stops as soon as it is provable that the distance will be greater than `max`.
+/// This makes it much cheaper to filter a large list of candidates down to only the close
+/// matches, as wildly unrelated strings are rejected early.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_within;
+/// assert_eq!(Some(1), levenshtein_distance_within("rust", "rusty", 2));
+/// assert_eq!(None, levenshtein_distance_within("rust", "typography", 2));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_within(source: &str, target: &str, max: usize) -> Option<usize> {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let target_chars = target.len();
+    let source_chars = source.len();
+    // The distance is always at least the difference in lengths, because we'd need at least
+    // that many insertions or deletions to make the lengths match, so we can reject inputs
+    // without doing any of the main computation if this is already over the threshold.
+    if source_chars.abs_diff(target_chars) > max {
+        return None;
+    }
+    // As with levenshtein_distance, empty inputs are all insertions or deletions, and we've
+    // already checked the length difference is within the threshold.
+    if source.is_empty() {
+        return Some(target_chars);
+    }
+    if target.is_empty() {
+        return Some(source_chars);
+    }
+
+    // As with levenshtein_distance, the distance is symmetric so we make the shorter input the
+    // `target` to keep the row of edit distances as small as possible
+    let (source, target) = if target_chars > source_chars {
+        (target, source)
+    } else {
+        (source, target)
+    };
+    let target_chars = target.len();
+
+    // This is the same single row recurrence as levenshtein_distance, overwriting the row in
+    // place and carrying the diagonal entry along in a temporary
+    let mut edit_distances = (0..=target_chars).collect::<Vec<usize>>();
+
+    for (i, source_char) in source.iter().enumerate() {
+        let mut previous_diagonal = edit_distances[0];
+        edit_distances[0] = i + 1;
+        // Track the smallest distance in this row as we go
+        let mut row_minimum = edit_distances[0];
+
+        for (j, target_char) in target.iter().enumerate() {
+            let above = edit_distances[j + 1];
+            let deletion = above + 1;
+            let insertion = edit_distances[j] + 1;
+            let substitution = if source_char == target_char {
+                previous_diagonal
+            } else {
+                previous_diagonal + 1
+            };
+            edit_distances[j + 1] =
+                core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+            row_minimum = core::cmp::min(row_minimum, edit_distances[j + 1]);
+            previous_diagonal = above;
+        }
+
+        // Every entry in the next row is computed from an entry in this row plus 0 or 1, so
+        // the distances never decrease as we go down the matrix. If every entry in this row is
+        // already over the threshold then the final distance must be too.
+        if row_minimum > max {
+            return None;
+        }
+    }
+
+    let distance = edit_distances[target_chars];
+    if distance > max {
+        None
+    } else {
+        Some(distance)
+    }
+}
+
+/// The error returned by [try_levenshtein_distance] when the inputs are too large to compare.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TooLarge {
+    /// The product of the lengths of the inputs in characters, which is the number of entries
+    /// in the matrix of edit distances, saturating at [usize::MAX]
+    pub product: usize,
+    /// The largest product of the lengths of the inputs that was allowed
+    pub max_product: usize,
+}
+
+impl core::fmt::Display for TooLarge {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "inputs are too large to compare, the product of their lengths is {} but at most {} is allowed",
+            self.product, self.max_product
+        )
+    }
+}
+
+impl core::error::Error for TooLarge {}
+
+/// Returns the [levenshtein_distance] between the source and target strings, or an error
+/// without comparing them if the product of their lengths is greater than `max_product`.
+///
+/// The time taken to calculate the distance is proportional to the product of the lengths of
+/// the inputs, so a pair of megabyte long strings takes around a trillion steps. Services which
+/// compare untrusted input can use this to reject pathological inputs up front, instead of
+/// tying up a thread for minutes.
+///
+/// ```
+/// use fuzzy_string_distance::{try_levenshtein_distance, TooLarge};
+/// assert_eq!(Ok(3), try_levenshtein_distance("kitten", "sitting", 100));
+/// assert_eq!(
+///     Err(TooLarge { product: 42, max_product: 40 }),
+///     try_levenshtein_distance("kitten", "sitting", 40),
+/// );
+/// ```
+///
+/// The lengths are counted in characters. If either input is empty the product is 0, so it is
+/// never rejected, as the distance is just the length of the other input.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn try_levenshtein_distance(
+    source: &str,
+    target: &str,
+    max_product: usize,
+) -> Result<usize, TooLarge> {
+    let product = source
+        .chars()
+        .count()
+        .saturating_mul(target.chars().count());
+    if product > max_product {
+        return Err(TooLarge {
+            product,
+            max_product,
+        });
+    }
+    Ok(levenshtein_distance(source, target))
+}
+
+/// Returns a lower bound on the [levenshtein_distance] between the source and target strings,
+/// which is the difference in their lengths.
+///
+/// Converting one string to the other takes at least as many insertions or deletions as the
+/// difference in their lengths, so the distance can never be less than this. Unlike the
+/// distance itself this only needs to count the characters of each string, so it is a cheap
+/// way to rule out candidates before calling any of the more expensive distance functions.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, min_possible_distance};
+/// assert_eq!(8, min_possible_distance("rust", "ferrisground"));
+/// assert_eq!(10, levenshtein_distance("rust", "ferrisground"));
+/// let max = 2;
+/// let candidates = ["rusty", "trust", "ferrisground"]
+///     .into_iter()
+///     .filter(|candidate| min_possible_distance("rust", candidate) <= max)
+///     .filter(|candidate| levenshtein_distance("rust", candidate) <= max)
+///     .collect::<Vec<&str>>();
+/// assert_eq!(vec!["rusty", "trust"], candidates);
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn min_possible_distance(source: &str, target: &str) -> usize {
+    source.chars().count().abs_diff(target.chars().count())
+}
+
+/// Returns a lower bound and an upper bound on the [levenshtein_distance] between the source
+/// and target strings, which are the difference in their lengths and the length of the longer
+/// string.
+///
+/// The lower bound is the same as [min_possible_distance]. The upper bound holds because we can
+/// always substitute every character of the shorter string and then insert or delete the rest.
+/// Both bounds only need the lengths of the strings, so in a two stage search you can skip
+/// candidates whose lower bound is already over your threshold, and accept candidates whose
+/// upper bound is already within it, only calculating the exact distance for the rest.
+///
+/// ```
+/// use fuzzy_string_distance::distance_bounds;
+/// assert_eq!((1, 7), distance_bounds("kitten", "sitting"));
+/// // Both bounds are reached for some strings
+/// assert_eq!((2, 4), distance_bounds("rust", "ru"));
+/// assert_eq!((2, 4), distance_bounds("rust", "xy"));
+/// ```
+///
+/// Neither bound can be improved on without looking at the characters themselves, as the lower
+/// bound is the exact distance when the shorter string is a subsequence of the longer one, and
+/// the upper bound is the exact distance when the strings have no characters in common.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn distance_bounds(source: &str, target: &str) -> (usize, usize) {
+    let source_chars = source.chars().count();
+    let target_chars = target.chars().count();
+    (
+        source_chars.abs_diff(target_chars),
+        core::cmp::max(source_chars, target_chars),
+    )
+}
+
+/// Returns the Levenshtein distance between the source and target strings if it is no greater
+/// than `k`, or `None` if the distance exceeds `k`, only computing a diagonal band of the edit
+/// distance matrix.
+///
+/// This returns the same results as [levenshtein_distance_within], but instead of computing every
+/// entry in the matrix it only computes the entries within `k` of the diagonal, as any path
+/// through the matrix that strays further than `k` from the diagonal must already have made more
+/// than `k` insertions or deletions. This brings the work down from proportional to the product
+/// of the two lengths to proportional to `k` times the length, which is much faster when looking
+/// for near matches with a small `k`.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_banded;
+/// assert_eq!(Some(3), levenshtein_distance_banded("kitten", "sitting", 3));
+/// assert_eq!(None, levenshtein_distance_banded("kitten", "sitting", 2));
+/// ```
+///
+/// See also:
+/// - [Ukkonen, Algorithms for approximate string matching](https://doi.org/10.1016/S0019-9958(85)80046-2)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_banded(source: &str, target: &str, k: usize) -> Option<usize> {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let target_chars = target.len();
+    let source_chars = source.len();
+    // If the lengths differ by more than k then the final entry in the matrix is outside the
+    // band, and the distance must be greater than k.
+    if source_chars.abs_diff(target_chars) > k {
+        return None;
+    }
+    if source.is_empty() {
+        return Some(target_chars);
+    }
+    if target.is_empty() {
+        return Some(source_chars);
+    }
+
+    // Any distance over k is as good as infinite to us, so we cap every entry at k + 1. This
+    // lets us treat all the entries outside the band as k + 1 without worrying about overflow.
+    let infinity = k.saturating_add(1);
+
+    // Unlike levenshtein_distance we swap between two rows rather than allocate a new row for
+    // each prefix of `source`, so that we only ever touch the entries in the band after
+    // initialising the rows.
+    let mut edit_distances = (0..=target_chars)
+        .map(|j| core::cmp::min(j, infinity))
+        .collect::<Vec<usize>>();
+    let mut new_edit_distances = vec![infinity; target_chars + 1];
+
+    for (i, source_char) in source.iter().enumerate() {
+        // We're calculating A[i + 1, j + 1] for the j where j + 1 is within k of i + 1
+        let first = (i + 1).saturating_sub(k);
+        let last = core::cmp::min(target_chars, (i + 1).saturating_add(k));
+        if first == 0 {
+            new_edit_distances[0] = core::cmp::min(i + 1, infinity);
+        } else {
+            // The entry just before the band is outside it, but will still hold a value from two
+            // rows ago, so reset it as we'll read it for the insertion case below.
+            new_edit_distances[first - 1] = infinity;
+        }
+
+        for j in core::cmp::max(first, 1)..=last {
+            let deletion = edit_distances[j].saturating_add(1);
+            let insertion = new_edit_distances[j - 1].saturating_add(1);
+            let substitution = if *source_char == target[j - 1] {
+                edit_distances[j - 1]
+            } else {
+                edit_distances[j - 1].saturating_add(1)
+            };
+            new_edit_distances[j] = core::cmp::min(
+                infinity,
+                core::cmp::min(deletion, core::cmp::min(insertion, substitution)),
+            );
+        }
+
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+
+    let distance = edit_distances[target_chars];
+    if distance > k {
+        None
+    } else {
+        Some(distance)
+    }
+}
+
 /// Returns the minimum number of single character insertions, deletions or substitutions
 /// required to convert the source string to the target string, known as the Levenshtein distance,
 /// ignoring ASCII case differences.
@@ -109,6 +913,9 @@ pub fn levenshtein_distance(source: &str, target: &str) -> usize {
 /// of 0 means the strings are equal and the distance can be up to the length of the longer
 /// string if they are completely unrelated.
 ///
+/// Only the ASCII letters A to Z are folded, see [levenshtein_distance_ignore_case] to ignore
+/// case differences in any script.
+///
 /// See also:
 /// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
 ///
@@ -116,7 +923,40 @@ pub fn levenshtein_distance(source: &str, target: &str) -> usize {
 /// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
 /// still does not compare grapheme clusters.
 pub fn levenshtein_distance_ignore_ascii_case(source: &str, target: &str) -> usize {
-    levenshtein_distance(&source.to_ascii_lowercase(), &target.to_ascii_lowercase())
+    levenshtein_distance(
+        &case::to_ascii_lowercase(source),
+        &case::to_ascii_lowercase(target),
+    )
+}
+
+/// Returns the minimum number of single character insertions, deletions or substitutions
+/// required to convert the source string to the target string, known as the Levenshtein distance,
+/// ignoring case differences.
+///
+/// Unlike [levenshtein_distance_ignore_ascii_case], which only folds the ASCII letters A to Z,
+/// this lowercases both inputs with [str::to_lowercase] so case differences in any script are
+/// ignored. This is slower than the ASCII version, so if your inputs are known to be ASCII you
+/// may prefer that instead. Lowercasing is not the same as full case folding, so strings such as
+/// "STRASSE" and "straße" which only match after case folding are still some edits apart. See
+/// [levenshtein_distance_ignore_case_locale] for languages such as Turkish where the default
+/// lowercasing is wrong.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance_ignore_case, levenshtein_distance_ignore_ascii_case};
+/// assert_eq!(0, levenshtein_distance_ignore_case("CAFÉ", "café"));
+/// // É is not an ASCII character so is not lowercased by the ASCII version
+/// assert_eq!(1, levenshtein_distance_ignore_ascii_case("CAFÉ", "café"));
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters. Lowercasing can also change the number of
+/// characters in a string, for instance 'İ' lowercases to 'i' followed by a combining dot.
+pub fn levenshtein_distance_ignore_case(source: &str, target: &str) -> usize {
+    levenshtein_distance(&case::to_lowercase(source), &case::to_lowercase(target))
 }
 
 /// A modified Levenshtein distance that matches from the source string to an arbitrary substring
@@ -148,11 +988,15 @@ pub fn levenshtein_distance_ignore_ascii_case(source: &str, target: &str) -> usi
 /// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
 /// still does not compare grapheme clusters.
 pub fn local_levenshtein_distance(source: &str, target: &str) -> usize {
+    // Collect the characters of both inputs once up front so we can index into them in the
+    // main loop without re-walking the UTF-8 strings
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
     // If either input is empty then the shortest transformation is all deletions or insertions
     // from/to an empty string.
     // This check also guards against any index out of bounds issues in the main implementation
-    let target_chars = target.chars().count();
-    let source_chars = source.chars().count();
+    let target_chars = target.len();
+    let source_chars = source.len();
     if source.is_empty() {
         // We can trivially match a 0 length substring in target with no edits
         return 0;
@@ -177,7 +1021,7 @@ pub fn local_levenshtein_distance(source: &str, target: &str) -> usize {
     // starting a match further into the string. If the target was "racecar" and the source was
     // "car" this should also be 0 edits.
 
-    for i in 0..source_chars {
+    for (i, source_char) in source.iter().enumerate() {
         // Step through each subsequent row of the matrix of edit distances, each time looking at
         // a prefix of `source` one character longer
         let mut new_edit_distances = vec![0; target_chars + 1];
@@ -186,7 +1030,7 @@ pub fn local_levenshtein_distance(source: &str, target: &str) -> usize {
         // source.
         new_edit_distances[0] = i + 1;
 
-        for j in 0..target_chars {
+        for (j, target_char) in target.iter().enumerate() {
             // Step through columns for the prefixes of `target` on this prefix of `source` row.
             // For a source of "kitten" and a target of "sitting", if we were up to i = 1 and
             // j = 2 then this would look like a source of "ki" we already have the distance for
@@ -200,10 +1044,6 @@ pub fn local_levenshtein_distance(source: &str, target: &str) -> usize {
             // At A[i + 1, j] we have the cost to reach a shorter `target` prefix with the same
             // source, so we can insert the extra character and the distance could be 1 greater
             let insertion = new_edit_distances[j] + 1;
-            // We can unwrap here because we're taking an element from both iterators within
-            // their respective bounds of 0 to source_chars -1 and target_chars - 1
-            let source_char = source.chars().skip(i).next().unwrap();
-            let target_char = target.chars().skip(j).next().unwrap();
             let substitution = if source_char == target_char {
                 // If the `source` character at i and the `target` character at j match, we
                 // don't need to transform anything
@@ -216,9 +1056,8 @@ pub fn local_levenshtein_distance(source: &str, target: &str) -> usize {
 
             // We always pick the cheapest option from the 3 we could do, which populates
             // A[i + 1, j + 1]
-            new_edit_distances[j + 1] = std::cmp::min(
-                deletion, std::cmp::min(insertion, substitution)
-            );
+            new_edit_distances[j + 1] =
+                core::cmp::min(deletion, core::cmp::min(insertion, substitution));
         }
 
         edit_distances = new_edit_distances;
@@ -262,10 +1101,40 @@ pub fn local_levenshtein_distance(source: &str, target: &str) -> usize {
 /// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
 /// still does not compare grapheme clusters.
 pub fn local_levenshtein_distance_ignore_ascii_case(source: &str, target: &str) -> usize {
-    local_levenshtein_distance(&source.to_ascii_lowercase(), &target.to_ascii_lowercase())
+    local_levenshtein_distance(
+        &case::to_ascii_lowercase(source),
+        &case::to_ascii_lowercase(target),
+    )
+}
+
+/// A modified Levenshtein distance that matches from the source string to an arbitrary substring
+/// of the target string, returning the minimum number of single character insertions, deletions
+/// or substitutions required to convert the source string to match any substring in the target,
+/// ignoring case differences
+///
+/// Unlike [local_levenshtein_distance_ignore_ascii_case], which only folds the ASCII letters A to
+/// Z, this lowercases both inputs with [str::to_lowercase] so case differences in any script are
+/// ignored. Otherwise this behaves the same as [local_levenshtein_distance].
+///
+/// ```
+/// use fuzzy_string_distance::local_levenshtein_distance_ignore_case;
+/// assert_eq!(0, local_levenshtein_distance_ignore_case("CAFÉ", "my favorite café bar"));
+/// assert_eq!(0, local_levenshtein_distance_ignore_case("ΣΟΦΊΑ", "η σοφία"));
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+/// - [Fuzzy Substring Matching: On-device Fuzzy Friend Search at Snapchat](http://arxiv.org/pdf/2211.02767)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn local_levenshtein_distance_ignore_case(source: &str, target: &str) -> usize {
+    local_levenshtein_distance(&case::to_lowercase(source), &case::to_lowercase(target))
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow)]
 mod tests {
     use super::*;
 
@@ -323,6 +1192,16 @@ mod tests {
         assert_eq!(result, 7);
     }
 
+    #[test]
+    fn ignoring_unicode_case() {
+        let result = levenshtein_distance_ignore_case("CAFÉ", "café");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_ignore_ascii_case("CAFÉ", "café");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_ignore_case("unrelated", "SCREAMING");
+        assert_eq!(result, 7);
+    }
+
     #[test]
     fn non_english() {
         let result = levenshtein_distance(&"El delfín español", &"Dolphin");
@@ -336,6 +1215,257 @@ mod tests {
         assert_eq!(result, 2);
     }
 
+    #[test]
+    fn reusing_buffers() {
+        let mut levenshtein = Levenshtein::new();
+        // Longer inputs first so the buffers have stale values in them for the shorter ones
+        assert_eq!(levenshtein.distance("El delfín español", "Dolphin"), 15);
+        assert_eq!(levenshtein.distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein.distance("bug", ""), 3);
+        assert_eq!(levenshtein.distance("rust", "rusty"), 1);
+    }
+
+    #[test]
+    fn argument_order() {
+        let long = "a much longer sentence than the other input";
+        let short = "sentence";
+        assert_eq!(
+            levenshtein_distance(long, short),
+            levenshtein_distance(short, long)
+        );
+        assert_eq!(levenshtein_distance(short, long), 35);
+    }
+
+    #[test]
+    fn slices() {
+        let result = levenshtein_distance_slice(&[1, 2, 3, 4], &[1, 3, 4, 5]);
+        assert_eq!(result, 2);
+        let result = levenshtein_distance_slice::<u32>(&[], &[1, 2]);
+        assert_eq!(result, 2);
+        let source = "El delfín español"
+            .chars()
+            .map(u32::from)
+            .collect::<Vec<u32>>();
+        let target = "Dolphin".chars().map(u32::from).collect::<Vec<u32>>();
+        assert_eq!(levenshtein_distance_slice(&source, &target), 15);
+    }
+
+    #[test]
+    fn slices_by() {
+        let source = ["cargo", "BUILD", "--release"];
+        let target = ["Cargo", "build", "--Release"];
+        let ignore_case = |a: &&str, b: &&str| a.eq_ignore_ascii_case(b);
+        let result = levenshtein_distance_slice_by(&source, &target, ignore_case);
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_slice_by(&source, &target, |a, b| a == b);
+        assert_eq!(result, 3);
+        // Compare only the first field
+        let source = [(1, 'a'), (2, 'b')];
+        let target = [(1, 'x'), (3, 'b')];
+        let result = levenshtein_distance_slice_by(&source, &target, |a, b| a.0 == b.0);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn ascii_bytes() {
+        for (source, target) in [("kitten", "sitting"), ("", "rust"), ("Dolphin", "dolphins")] {
+            let result = levenshtein_distance_ascii(source.as_bytes(), target.as_bytes());
+            assert_eq!(result, levenshtein_distance(source, target));
+        }
+    }
+
+    #[test]
+    fn iterators() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("", "rust"),
+            ("bug", ""),
+            ("El delfín español", "Dolphin"),
+        ];
+        for (source, target) in pairs {
+            let expected = levenshtein_distance(source, target);
+            let result = levenshtein_distance_iter(source.chars(), target.chars());
+            assert_eq!(result, expected);
+            let result = levenshtein_distance_iter(target.chars().rev(), source.chars().rev());
+            assert_eq!(result, expected);
+        }
+        let result = levenshtein_distance_iter(core::iter::repeat_n('a', 3), ['a', 'b']);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn invalid_utf8_bytes() {
+        let result = levenshtein_distance_bytes(b"disk full\xFF", b"disk full");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_bytes(b"\xFF\xFE", b"\xFE\xFF");
+        assert_eq!(result, 2);
+        // The first byte of a truncated multi byte sequence is invalid on its own, and an
+        // invalid byte is never equal to a character, even the character with the same value
+        let result = levenshtein_distance_bytes(b"caf\xC3", b"caf\xC3\xA9");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_bytes(b"\xE9", "\u{e9}".as_bytes());
+        assert_eq!(result, 1);
+        for (source, target) in [("kitten", "sitting"), ("El delfín español", "Dolphin")] {
+            let result = levenshtein_distance_bytes(source.as_bytes(), target.as_bytes());
+            assert_eq!(result, levenshtein_distance(source, target));
+        }
+    }
+
+    #[test]
+    fn saturating_u8() {
+        let result = levenshtein_distance_u8("kitten", "sitting");
+        assert_eq!(result, 3);
+        let result = levenshtein_distance_u8("", "rust");
+        assert_eq!(result, 4);
+        let result = levenshtein_distance_u8("El delfín español", "Dolphin");
+        assert_eq!(
+            usize::from(result),
+            levenshtein_distance("El delfín español", "Dolphin")
+        );
+        for length in [254, 255, 256, 1000] {
+            let source = "a".repeat(length);
+            let target = "b".repeat(length / 2);
+            let expected = core::cmp::min(levenshtein_distance(&source, &target), 255);
+            assert_eq!(
+                usize::from(levenshtein_distance_u8(&source, &target)),
+                expected
+            );
+            assert_eq!(
+                usize::from(levenshtein_distance_u8(&target, &source)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn rejecting_large_inputs() {
+        let result = try_levenshtein_distance("kitten", "sitting", 42);
+        assert_eq!(result, Ok(3));
+        let result = try_levenshtein_distance("kitten", "sitting", 41);
+        let error = TooLarge {
+            product: 42,
+            max_product: 41,
+        };
+        assert_eq!(result, Err(error));
+        assert_eq!(
+            error.to_string(),
+            "inputs are too large to compare, the product of their lengths is 42 but at most 41 is allowed"
+        );
+        let long = "a".repeat(1000);
+        let result = try_levenshtein_distance(&long, "", 0);
+        assert_eq!(result, Ok(1000));
+    }
+
+    #[test]
+    fn within_threshold() {
+        let result = levenshtein_distance_within("kitten", "sitting", 3);
+        assert_eq!(result, Some(3));
+        let result = levenshtein_distance_within("kitten", "sitting", 2);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn within_threshold_length_difference() {
+        // Length difference alone exceeds the threshold
+        let result = levenshtein_distance_within("bug", "", 2);
+        assert_eq!(result, None);
+        let result = levenshtein_distance_within("", "rust", 4);
+        assert_eq!(result, Some(4));
+    }
+
+    #[test]
+    fn within_threshold_matches_full_distance() {
+        let pairs = [
+            ("ferrisground", "run"),
+            ("Edit distance", "Eddy"),
+            ("unrelated", "SCREAMING"),
+            ("El delfín español", "Dolphin"),
+        ];
+        for (source, target) in pairs {
+            let distance = levenshtein_distance(source, target);
+            for max in 0..20 {
+                let expected = if distance <= max {
+                    Some(distance)
+                } else {
+                    None
+                };
+                assert_eq!(levenshtein_distance_within(source, target, max), expected);
+                assert_eq!(levenshtein_distance_within(target, source, max), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn min_possible_distance_is_lower_bound() {
+        let alphabet = ['a', 'b', 'c', 'é'];
+        let mut state = 7_u32;
+        let mut next = || {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (state >> 16) as usize
+        };
+        for _ in 0..2000 {
+            let mut random_string = || {
+                let length = next() % 12;
+                (0..length)
+                    .map(|_| alphabet[next() % alphabet.len()])
+                    .collect::<String>()
+            };
+            let source = random_string();
+            let target = random_string();
+            let bound = min_possible_distance(&source, &target);
+            assert!(bound <= levenshtein_distance(&source, &target));
+        }
+        assert_eq!(min_possible_distance("", "rust"), 4);
+        assert_eq!(min_possible_distance("delfín", "dolphin"), 1);
+    }
+
+    #[test]
+    fn bounds_contain_distance() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("ferrisground", "run"),
+            ("unrelated", "SCREAMING"),
+            ("El delfín español", "Dolphin"),
+            ("", ""),
+        ];
+        for (source, target) in pairs {
+            let (lower, upper) = distance_bounds(source, target);
+            let distance = levenshtein_distance(source, target);
+            assert!(lower <= distance && distance <= upper);
+            assert_eq!(lower, min_possible_distance(source, target));
+        }
+        // Reaching the bounds
+        assert_eq!(levenshtein_distance("rust", "ru"), 2);
+        assert_eq!(levenshtein_distance("rust", "xy"), 4);
+    }
+
+    #[test]
+    fn banded_matches_full_distance() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("ferrisground", "run"),
+            ("Edit distance", "Eddy"),
+            ("unrelated", "SCREAMING"),
+            ("El delfín español", "Dolphin"),
+            ("typography", "typpgrapy"),
+            ("", "rust"),
+        ];
+        for (source, target) in pairs {
+            let distance = levenshtein_distance(source, target);
+            for k in 0..20 {
+                let expected = if distance <= k { Some(distance) } else { None };
+                assert_eq!(levenshtein_distance_banded(source, target, k), expected);
+                assert_eq!(levenshtein_distance_banded(target, source, k), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn banded_unbounded() {
+        let result = levenshtein_distance_banded("kitten", "sitting", usize::MAX);
+        assert_eq!(result, Some(3));
+    }
+
     #[test]
     fn non_english_local() {
         let result = local_levenshtein_distance(&"Dolphin", &"El delfín español");
@@ -377,4 +1507,12 @@ mod tests {
         let result = local_levenshtein_distance_ignore_ascii_case(&"SCREAM", &"unrelated");
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn search_term_ignoring_unicode_case() {
+        let result = local_levenshtein_distance_ignore_case("CAFÉ", "my favorite café bar");
+        assert_eq!(result, 0);
+        let result = local_levenshtein_distance_ignore_ascii_case("CAFÉ", "my favorite café bar");
+        assert_eq!(result, 1);
+    }
 }
@@ -0,0 +1,807 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::EditOp;
+
+/// The best matching substring of a target string found by [local_levenshtein_match].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalMatch {
+    /// The [local_levenshtein_distance](crate::local_levenshtein_distance) of the match, which is
+    /// the minimum number of edits to convert the source string to the matched substring
+    pub distance: usize,
+    /// The byte offset in the target string where the matched substring starts
+    pub start: usize,
+    /// The byte offset in the target string where the matched substring ends, exclusive
+    pub end: usize,
+}
+
+impl LocalMatch {
+    /// Returns the fraction of the target string covered by the matched substring, between 0.0
+    /// and 1.0, where the target is the same string this match was found in.
+    ///
+    /// This is the number of characters in the matched substring divided by the number of
+    /// characters in the whole target. Both are counted in characters as per [str::chars]
+    /// rather than bytes, so non ASCII characters don't count for more than ASCII ones. This
+    /// can help rank matches of the same distance, as a query matching most of a target is
+    /// usually a better result than one matching a small fragment of it.
+    ///
+    /// ```
+    /// use fuzzy_string_distance::local_levenshtein_match;
+    /// let target = "A long sentence";
+    /// let result = local_levenshtein_match("lung", target);
+    /// assert_eq!(4.0 / 15.0, result.coverage(target));
+    /// let result = local_levenshtein_match("lung", "long");
+    /// assert_eq!(1.0, result.coverage("long"));
+    /// ```
+    ///
+    /// An empty target is fully covered by the empty match, so has a coverage of 1.0.
+    ///
+    /// # Panics
+    ///
+    /// If the start and end of the match are not on character boundaries in the target, or are
+    /// past its end, which can only happen if the target is not the string this match was found
+    /// in.
+    pub fn coverage(&self, target: &str) -> f64 {
+        let target_chars = target.chars().count();
+        if target_chars == 0 {
+            return 1.0;
+        }
+        let matched_chars = target[self.start..self.end].chars().count();
+        matched_chars as f64 / target_chars as f64
+    }
+}
+
+/// Returns the substring of the target string which the source string matches with the fewest
+/// single character insertions, deletions or substitutions, as a [LocalMatch] of the distance
+/// and the byte range of the substring within the target.
+///
+/// The distance is the same as [local_levenshtein_distance](crate::local_levenshtein_distance),
+/// and `&target[start..end]` is the substring that the source string is closest to.
+///
+/// ```
+/// use fuzzy_string_distance::{local_levenshtein_match, LocalMatch};
+/// let target = "A long sentence";
+/// let result = local_levenshtein_match("lung", target);
+/// assert_eq!(LocalMatch { distance: 1, start: 2, end: 6 }, result);
+/// assert_eq!("long", &target[result.start..result.end]);
+/// ```
+///
+/// If several substrings tie for the fewest edits, the leftmost one, which starts earliest in
+/// the target, is chosen, and if several of those start at the same place, the shortest of them
+/// is chosen.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn local_levenshtein_match(source: &str, target: &str) -> LocalMatch {
+    let source_chars = source.chars().collect::<Vec<char>>();
+    let target_chars = target.chars().collect::<Vec<char>>();
+    let (distance, start, end) = local_match_columns(&source_chars, &target_chars);
+    let byte_offsets = byte_offsets(target);
+    LocalMatch {
+        distance,
+        start: byte_offsets[start],
+        end: byte_offsets[end],
+    }
+}
+
+/// Returns true if the target string contains a substring which the query can be converted to
+/// with no more than `max_distance` single character insertions, deletions or substitutions.
+///
+/// This is a fuzzy [str::contains], and is the same as checking if the
+/// [local_levenshtein_distance](crate::local_levenshtein_distance) from the query to the target
+/// is at most `max_distance`, but stops as soon as every prefix of the query is further than
+/// `max_distance` from every substring of the target, as the distance can then only get worse.
+///
+/// ```
+/// use fuzzy_string_distance::fuzzy_contains;
+/// assert!(fuzzy_contains("lnog", "A long sentence", 2));
+/// assert!(!fuzzy_contains("lnog", "A long sentence", 1));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn fuzzy_contains(query: &str, target: &str, max_distance: usize) -> bool {
+    let query = query.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    // We can always delete the entire query to match the empty substring
+    if query.len() <= max_distance {
+        return true;
+    }
+
+    // As in the local Levenshtein distance, the first row is all zeros as we don't penalise
+    // starting the match anywhere in the target
+    let mut edit_distances = vec![0; target.len() + 1];
+    let mut new_edit_distances = vec![0; target.len() + 1];
+    for (i, query_char) in query.iter().enumerate() {
+        new_edit_distances[0] = i + 1;
+        let mut row_minimum = new_edit_distances[0];
+        for (j, target_char) in target.iter().enumerate() {
+            let deletion = edit_distances[j + 1] + 1;
+            let insertion = new_edit_distances[j] + 1;
+            let substitution = if query_char == target_char {
+                edit_distances[j]
+            } else {
+                edit_distances[j] + 1
+            };
+            new_edit_distances[j + 1] =
+                core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+            row_minimum = core::cmp::min(row_minimum, new_edit_distances[j + 1]);
+        }
+        // Every entry in the next row is at least the smallest entry in this row, as it is
+        // reached from this row with edits that never reduce the distance, so once the whole
+        // row is over the threshold the final row will be too
+        if row_minimum > max_distance {
+            return false;
+        }
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+    // The smallest entry in the final row is within the threshold or we would have stopped
+    true
+}
+
+/// Returns the byte ranges of the non overlapping substrings of the target string which the
+/// query can be converted to with no more than `max_distance` single character insertions,
+/// deletions or substitutions, in the order they appear in the target.
+///
+/// This is useful for highlighting every approximate occurrence of the query in the target,
+/// rather than just the best one as with [local_levenshtein_match].
+///
+/// ```
+/// use fuzzy_string_distance::fuzzy_find_all;
+/// let target = "my favourite colour is the color of the sky, not a colr";
+/// let matches = fuzzy_find_all("colour", target, 2);
+/// let found = matches
+///     .into_iter()
+///     .map(|range| &target[range])
+///     .collect::<Vec<&str>>();
+/// assert_eq!(vec!["colour", "color", "colr"], found);
+/// ```
+///
+/// For each place a match could end in the target, the closest match ending there is a
+/// candidate if it is within `max_distance` and no further from the query than the closest
+/// matches ending one character either side of it, preferring the shortest match if several
+/// ending there are equally close. Some of these candidates can still
+/// overlap, so they are chosen greedily from left to right: the candidate which starts earliest
+/// in the target is kept, picking the longest if several start at the same place, then every
+/// candidate which overlaps it is dropped, and so on through the rest of the target.
+///
+/// Empty matches are never returned, so if the query is empty, or short enough to be deleted
+/// entirely within `max_distance`, only the non empty matches are found.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn fuzzy_find_all(query: &str, target: &str, max_distance: usize) -> Vec<Range<usize>> {
+    let query_chars = query.chars().collect::<Vec<char>>();
+    let target_chars = target.chars().collect::<Vec<char>>();
+    let row = local_match_row(&query_chars, &target_chars, Tie::Latest);
+    // Extending a match by a character either side of it only costs one insertion, so the
+    // entries next to a close match are usually within the threshold too. Only keeping the
+    // ends where the distance is no greater than at the neighbouring ends stops those padded
+    // matches from crowding out the matches they were extended from.
+    let mut candidates = row
+        .iter()
+        .enumerate()
+        .filter(|&(end, &(distance, start))| {
+            let previous = end.checked_sub(1).map(|previous| row[previous].0);
+            let next = row.get(end + 1).map(|&(next, _)| next);
+            distance <= max_distance
+                && start < end
+                && previous.into_iter().all(|previous| distance <= previous)
+                && next.into_iter().all(|next| distance <= next)
+        })
+        .map(|(end, &(_, start))| start..end)
+        .collect::<Vec<Range<usize>>>();
+    // Earliest start first, then longest first for candidates that start at the same place
+    candidates.sort_by_key(|range| (range.start, core::cmp::Reverse(range.end)));
+
+    let byte_offsets = byte_offsets(target);
+    let mut matches: Vec<Range<usize>> = Vec::new();
+    let mut covered = 0;
+    for candidate in candidates {
+        if candidate.start >= covered {
+            covered = candidate.end;
+            matches.push(byte_offsets[candidate.start]..byte_offsets[candidate.end]);
+        }
+    }
+    matches
+}
+
+/// A modified Levenshtein distance that matches from the source string to an arbitrary suffix
+/// of the target string, returning the minimum number of single character insertions, deletions
+/// or substitutions required to convert the source string to match any suffix of the target.
+///
+/// This is like a fuzzy [str::ends_with]. As with
+/// [local_levenshtein_distance](crate::local_levenshtein_distance), any prefix of the target can
+/// be ignored for free, but the match has to extend all the way to the end of the target, which
+/// is useful for matching a file name against the end of a path.
+///
+/// ```
+/// use fuzzy_string_distance::{local_levenshtein_distance, suffix_levenshtein_distance};
+/// assert_eq!(1, suffix_levenshtein_distance("man.rs", "src/main.rs"));
+/// // src is a substring of the path, but we need 2 edits to match it against a suffix,
+/// // for instance deleting s and substituting c for s to match rs
+/// assert_eq!(0, local_levenshtein_distance("src", "src/main.rs"));
+/// assert_eq!(2, suffix_levenshtein_distance("src", "src/main.rs"));
+/// // suffix distance is asymmetric, here we have to delete the extra directory
+/// assert_eq!(4, suffix_levenshtein_distance("src/main.rs", "main.rs"));
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn suffix_levenshtein_distance(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    // The final entry of the final row is the match which extends to the end of the target
+    final_row(&source, &target, true)[target.len()]
+}
+
+/// A modified Levenshtein distance that matches from the source string to an arbitrary prefix
+/// of the target string, returning the minimum number of single character insertions, deletions
+/// or substitutions required to convert the source string to match any prefix of the target.
+///
+/// This is like a fuzzy [str::starts_with]. The match has to start at the beginning of the
+/// target, but any suffix of the target can be ignored for free, which is useful for matching
+/// what a user has typed so far against the start of each entry in a list.
+///
+/// ```
+/// use fuzzy_string_distance::{local_levenshtein_distance, prefix_levenshtein_distance};
+/// assert_eq!(0, prefix_levenshtein_distance("app", "application"));
+/// assert_eq!(1, prefix_levenshtein_distance("apl", "application"));
+/// // cat is a substring of the target, but we need 2 edits to match it against a prefix,
+/// // deleting c and t to match a
+/// assert_eq!(0, local_levenshtein_distance("cat", "application"));
+/// assert_eq!(2, prefix_levenshtein_distance("cat", "application"));
+/// // prefix distance is asymmetric, here we have to delete the rest of the word
+/// assert_eq!(8, prefix_levenshtein_distance("application", "app"));
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn prefix_levenshtein_distance(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    // The minimum in the final row is the match against the closest prefix of the target
+    final_row(&source, &target, false)
+        .into_iter()
+        .min()
+        .unwrap()
+}
+
+/// Runs the Levenshtein distance from the source to the target and returns the final row of
+/// the matrix of edit distances, where the entry at each column is the distance from the whole
+/// source to the target up to that column.
+///
+/// If `ignore_target_prefix` is true, the first row is all zeros as in the local Levenshtein
+/// distance, so the match can start anywhere in the target without penalty.
+fn final_row(source: &[char], target: &[char], ignore_target_prefix: bool) -> Vec<usize> {
+    let mut edit_distances = if ignore_target_prefix {
+        vec![0; target.len() + 1]
+    } else {
+        (0..=target.len()).collect()
+    };
+    let mut new_edit_distances = vec![0; target.len() + 1];
+    for (i, source_char) in source.iter().enumerate() {
+        // Converting this prefix of the source to an empty string is purely deletions
+        new_edit_distances[0] = i + 1;
+        for (j, target_char) in target.iter().enumerate() {
+            let deletion = edit_distances[j + 1] + 1;
+            let insertion = new_edit_distances[j] + 1;
+            let substitution = if source_char == target_char {
+                edit_distances[j]
+            } else {
+                edit_distances[j] + 1
+            };
+            new_edit_distances[j + 1] =
+                core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+        }
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+    edit_distances
+}
+
+/// Returns the substring of the target string which the source string matches with the fewest
+/// single character insertions, deletions or substitutions, as per [local_levenshtein_match],
+/// along with the edits which convert the source string to that substring.
+///
+/// The match is the same as [local_levenshtein_match] chooses, and the number of operations
+/// which are not [EditOp::Match] is its distance. As with [levenshtein_ops](crate::levenshtein_ops),
+/// the operations are in order from the start of the source string to the end, but they only
+/// cover the matched substring of the target, not the parts of the target either side of it.
+///
+/// ```
+/// use fuzzy_string_distance::{local_levenshtein_ops, EditOp, LocalMatch};
+/// let (result, ops) = local_levenshtein_ops("lung", "A long sentence");
+/// assert_eq!(LocalMatch { distance: 1, start: 2, end: 6 }, result);
+/// assert_eq!(
+///     vec![
+///         EditOp::Match('l'),
+///         EditOp::Substitute { from: 'u', to: 'o' },
+///         EditOp::Match('n'),
+///         EditOp::Match('g'),
+///     ],
+///     ops,
+/// );
+/// ```
+///
+/// This needs to store the edit chosen for every entry in the matrix of edit distances, which
+/// takes memory proportional to the product of the lengths of the inputs.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn local_levenshtein_ops(source: &str, target: &str) -> (LocalMatch, Vec<EditOp>) {
+    let source_chars = source.chars().collect::<Vec<char>>();
+    let target_chars = target.chars().collect::<Vec<char>>();
+    let (final_row, steps) = local_match_steps(&source_chars, &target_chars);
+    // The same choice of match as in local_match_columns
+    let (end, (distance, start)) = final_row
+        .into_iter()
+        .enumerate()
+        .min_by_key(|&(_, (distance, start))| (distance, start))
+        .unwrap();
+
+    // Follow the chosen edits back from the end of the match until we've used up the source,
+    // at which point we're at the start of the match on the first row
+    let mut ops = Vec::with_capacity(source_chars.len());
+    let mut i = source_chars.len();
+    let mut j = end;
+    while i > 0 {
+        // The first column is only reachable by deleting source characters
+        let step = if j == 0 {
+            Step::Delete
+        } else {
+            steps[i - 1][j - 1]
+        };
+        match step {
+            Step::Delete => {
+                ops.push(EditOp::Delete(source_chars[i - 1]));
+                i -= 1;
+            }
+            Step::Insert => {
+                ops.push(EditOp::Insert(target_chars[j - 1]));
+                j -= 1;
+            }
+            Step::Diagonal => {
+                let from = source_chars[i - 1];
+                let to = target_chars[j - 1];
+                ops.push(if from == to {
+                    EditOp::Match(from)
+                } else {
+                    EditOp::Substitute { from, to }
+                });
+                i -= 1;
+                j -= 1;
+            }
+        }
+    }
+    ops.reverse();
+
+    let byte_offsets = byte_offsets(target);
+    let local_match = LocalMatch {
+        distance,
+        start: byte_offsets[start],
+        end: byte_offsets[end],
+    };
+    (local_match, ops)
+}
+
+/// Returns the byte offset of every character in the string, followed by the length of the
+/// string, so that the column of a matrix of edit distances can be converted to a byte offset.
+pub(crate) fn byte_offsets(string: &str) -> Vec<usize> {
+    string
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(core::iter::once(string.len()))
+        .collect()
+}
+
+/// Runs the local Levenshtein distance, tracking for each entry where in the target the
+/// cheapest match to that entry started, and returns the distance, start and end columns of
+/// the best match.
+fn local_match_columns(source: &[char], target: &[char]) -> (usize, usize, usize) {
+    // The minimum in the final row is the best match. Each entry holds the earliest start of
+    // the cheapest matches ending there, so we take the leftmost start among the cheapest, and
+    // min_by_key returns the first of any equal minimums, which is the shortest of those
+    let (end, (distance, start)) = local_match_row(source, target, Tie::Earliest)
+        .into_iter()
+        .enumerate()
+        .min_by_key(|&(_, (distance, start))| (distance, start))
+        .unwrap();
+    (distance, start, end)
+}
+
+/// Runs the local Levenshtein distance, tracking for each entry where in the target the
+/// cheapest match to that entry started, and returns the final row of distances and start
+/// columns, so the entry at each column is the best match ending at that column.
+fn local_match_row(source: &[char], target: &[char], tie: Tie) -> Vec<(usize, usize)> {
+    let target_chars = target.len();
+    // As in the local Levenshtein distance, the first row is all zeros as we don't penalise
+    // starting the match anywhere in the target. Alongside each distance we store the column
+    // the match started at, which for the first row is the column itself as no characters of
+    // the target have been matched yet.
+    let mut edit_distances = (0..=target_chars)
+        .map(|j| (0, j))
+        .collect::<Vec<(usize, usize)>>();
+    let mut new_edit_distances = vec![(0, 0); target_chars + 1];
+
+    for (i, source_char) in source.iter().enumerate() {
+        // Deleting the entire prefix of `source` matches the empty substring at the start
+        new_edit_distances[0] = (i + 1, 0);
+
+        for (j, target_char) in target.iter().enumerate() {
+            let (cell, _) = local_match_cell(
+                edit_distances[j + 1],
+                new_edit_distances[j],
+                edit_distances[j],
+                source_char == target_char,
+                tie,
+            );
+            new_edit_distances[j + 1] = cell;
+        }
+
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+
+    edit_distances
+}
+
+/// The edit that was chosen to reach an entry in the matrix of edit distances.
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    /// From the entry above, deleting a source character
+    Delete,
+    /// From the entry to the left, inserting a target character
+    Insert,
+    /// From the entry diagonally above and to the left, matching or substituting a character
+    Diagonal,
+}
+
+/// Which start column to keep when several ways to reach an entry in the matrix of edit
+/// distances are equally cheap.
+#[derive(Clone, Copy, Debug)]
+enum Tie {
+    /// Keep the match that started earliest, so the leftmost match wins
+    Earliest,
+    /// Keep the match that started latest, so the shortest match wins
+    Latest,
+}
+
+/// Calculates an entry of the local Levenshtein distance from the distance and start column of
+/// the entries above, to the left, and diagonally above and to the left of it, and whether the
+/// source and target characters for this entry are equal. Returns the distance and start
+/// column of the entry, along with the edit that was chosen to reach it.
+fn local_match_cell(
+    (above, above_start): (usize, usize),
+    (left, left_start): (usize, usize),
+    (diagonal, diagonal_start): (usize, usize),
+    chars_match: bool,
+    tie: Tie,
+) -> ((usize, usize), Step) {
+    let deletion = ((above + 1, above_start), Step::Delete);
+    let insertion = ((left + 1, left_start), Step::Insert);
+    let substitution = if chars_match {
+        ((diagonal, diagonal_start), Step::Diagonal)
+    } else {
+        ((diagonal + 1, diagonal_start), Step::Diagonal)
+    };
+    // Pick the cheapest option, and if there's a tie pick the start column the caller prefers
+    [deletion, insertion, substitution]
+        .into_iter()
+        .min_by(|&((a, a_start), _), &((b, b_start), _)| {
+            a.cmp(&b).then(match tie {
+                Tie::Earliest => a_start.cmp(&b_start),
+                Tie::Latest => b_start.cmp(&a_start),
+            })
+        })
+        .unwrap()
+}
+
+/// Runs the local Levenshtein distance as in [local_match_row], but also stores the edit chosen
+/// for every entry in the matrix so that the best match can be followed back to its start.
+/// Returns the final row along with the edits, where the edit for the entry at row `i + 1` and
+/// column `j + 1` is at `[i][j]`.
+fn local_match_steps(source: &[char], target: &[char]) -> (Vec<(usize, usize)>, Vec<Vec<Step>>) {
+    let mut edit_distances = (0..=target.len())
+        .map(|j| (0, j))
+        .collect::<Vec<(usize, usize)>>();
+    let mut new_edit_distances = vec![(0, 0); target.len() + 1];
+    let mut steps = Vec::with_capacity(source.len());
+
+    for (i, source_char) in source.iter().enumerate() {
+        new_edit_distances[0] = (i + 1, 0);
+        let mut row = Vec::with_capacity(target.len());
+        for (j, target_char) in target.iter().enumerate() {
+            let (cell, step) = local_match_cell(
+                edit_distances[j + 1],
+                new_edit_distances[j],
+                edit_distances[j],
+                source_char == target_char,
+                Tie::Earliest,
+            );
+            new_edit_distances[j + 1] = cell;
+            row.push(step);
+        }
+        steps.push(row);
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+
+    (edit_distances, steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_levenshtein_distance;
+
+    #[test]
+    fn matching_substring() {
+        let target = "A long sentence";
+        let result = local_levenshtein_match("long", target);
+        assert_eq!(
+            result,
+            LocalMatch {
+                distance: 0,
+                start: 2,
+                end: 6
+            }
+        );
+    }
+
+    #[test]
+    fn matching_non_english() {
+        let target = "El delfín español";
+        let result = local_levenshtein_match("Dolphin", target);
+        assert_eq!(
+            result.distance,
+            local_levenshtein_distance("Dolphin", target)
+        );
+        // delfín -> Dolphin is 5 edits, and so is the shorter lfín -> Dolphin, but delfín starts
+        // earlier
+        assert_eq!(&target[result.start..result.end], "delfín");
+    }
+
+    #[test]
+    fn matching_ties() {
+        // Matches both the first and last 'a' equally, pick the first
+        let result = local_levenshtein_match("a", "banana");
+        assert_eq!(
+            result,
+            LocalMatch {
+                distance: 0,
+                start: 1,
+                end: 2
+            }
+        );
+        // Can match "ya" by substituting x or "a" by deleting x, pick the leftmost
+        let result = local_levenshtein_match("xa", "ya");
+        assert_eq!(
+            result,
+            LocalMatch {
+                distance: 1,
+                start: 0,
+                end: 2
+            }
+        );
+        // Can match "axb", "ax" or "a" from the start with one edit, pick the shortest
+        let result = local_levenshtein_match("ab", "axb");
+        assert_eq!(
+            result,
+            LocalMatch {
+                distance: 1,
+                start: 0,
+                end: 1
+            }
+        );
+    }
+
+    #[test]
+    fn contains_with_typo() {
+        let target = "The quick brown fox jumps over the lazy dog";
+        assert!(fuzzy_contains("jumsp", target, 2));
+        assert!(fuzzy_contains("browm", target, 1));
+        assert!(!fuzzy_contains("browm", target, 0));
+        assert!(!fuzzy_contains("elephant", target, 1));
+    }
+
+    #[test]
+    fn contains_same_as_local_distance() {
+        let target = "El delfín español";
+        for query in ["Dolphin", "delfin", "", "español", "El delfín español!"] {
+            let distance = local_levenshtein_distance(query, target);
+            for max_distance in 0..10 {
+                assert_eq!(
+                    fuzzy_contains(query, target, max_distance),
+                    distance <= max_distance
+                );
+            }
+        }
+        assert!(fuzzy_contains("", "", 0));
+        assert!(!fuzzy_contains("rust", "", 3));
+    }
+
+    #[test]
+    fn find_all() {
+        let target = "the cat sat on the mat";
+        let result = fuzzy_find_all("cat", target, 0);
+        assert_eq!(result, vec![4..7]);
+        // sat and mat are one substitution away, but at is one deletion away and shorter
+        let result = fuzzy_find_all("cat", target, 1);
+        assert_eq!(result, vec![4..7, 9..11, 20..22]);
+        let result = fuzzy_find_all("dog", target, 1);
+        assert_eq!(result, vec![]);
+    }
+
+    #[test]
+    fn find_all_overlapping() {
+        // ana appears at 1..4 and 3..6, which overlap, so only the first is kept
+        let result = fuzzy_find_all("ana", "banana", 1);
+        assert_eq!(result, vec![1..4]);
+        // a and an both start at 1 within 2 edits of ana, the longer ana wins
+        let result = fuzzy_find_all("ana", "banana", 2);
+        assert_eq!(result, vec![1..4]);
+        let result = fuzzy_find_all("ana", "banana ana", 0);
+        assert_eq!(result, vec![1..4, 7..10]);
+    }
+
+    #[test]
+    fn find_all_non_english() {
+        let target = "El delfín español y el delfin";
+        let result = fuzzy_find_all("delfín", target, 1);
+        assert_eq!(result, vec![3..10, 25..31]);
+        assert_eq!(&target[result[0].clone()], "delfín");
+        let result = fuzzy_find_all("", target, 0);
+        assert_eq!(result, vec![]);
+    }
+
+    #[test]
+    fn suffix() {
+        let result = suffix_levenshtein_distance("main.rs", "src/main.rs");
+        assert_eq!(result, 0);
+        let result = suffix_levenshtein_distance("main", "src/main.rs");
+        assert_eq!(result, 3);
+        let result = suffix_levenshtein_distance("src/main.rs", "main.rs");
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn suffix_empty() {
+        let result = suffix_levenshtein_distance("", "src/main.rs");
+        assert_eq!(result, 0);
+        let result = suffix_levenshtein_distance("main.rs", "");
+        assert_eq!(result, 7);
+        let result = suffix_levenshtein_distance("español", "El delfín español");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn prefix() {
+        let result = prefix_levenshtein_distance("app", "application");
+        assert_eq!(result, 0);
+        let result = prefix_levenshtein_distance("aplication", "application");
+        assert_eq!(result, 1);
+        let result = prefix_levenshtein_distance("tion", "application");
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn prefix_empty() {
+        let result = prefix_levenshtein_distance("", "application");
+        assert_eq!(result, 0);
+        let result = prefix_levenshtein_distance("app", "");
+        assert_eq!(result, 3);
+        let result = prefix_levenshtein_distance("El delfín", "El delfín español");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn ops_same_as_match() {
+        let pairs = [
+            ("long", "A long sentence"),
+            ("Dolphin", "El delfín español"),
+            ("a", "banana"),
+            ("xa", "ya"),
+            ("ab", "axb"),
+            ("", "rust"),
+            ("rust", ""),
+            ("A long sentence", "long"),
+        ];
+        for (source, target) in pairs {
+            let (result, ops) = local_levenshtein_ops(source, target);
+            assert_eq!(result, local_levenshtein_match(source, target));
+            let edits = ops
+                .iter()
+                .filter(|op| !matches!(op, EditOp::Match(_)))
+                .count();
+            assert_eq!(edits, result.distance);
+        }
+    }
+
+    #[test]
+    fn ops_transform_to_match() {
+        let pairs = [
+            ("Dolphin", "El delfín español"),
+            ("lung", "A long sentence"),
+            ("xa", "ya"),
+            ("A long sentence", "long"),
+        ];
+        for (source, target) in pairs {
+            let (result, ops) = local_levenshtein_ops(source, target);
+            let from = ops
+                .iter()
+                .filter_map(|op| match *op {
+                    EditOp::Match(c) | EditOp::Delete(c) => Some(c),
+                    EditOp::Substitute { from, .. } => Some(from),
+                    EditOp::Insert(_) => None,
+                })
+                .collect::<String>();
+            let to = ops
+                .iter()
+                .filter_map(|op| match *op {
+                    EditOp::Match(c) | EditOp::Insert(c) => Some(c),
+                    EditOp::Substitute { to, .. } => Some(to),
+                    EditOp::Delete(_) => None,
+                })
+                .collect::<String>();
+            assert_eq!(from, source);
+            assert_eq!(to, &target[result.start..result.end]);
+        }
+    }
+
+    #[test]
+    fn matching_empty() {
+        let result = local_levenshtein_match("", "A long sentence");
+        assert_eq!(
+            result,
+            LocalMatch {
+                distance: 0,
+                start: 0,
+                end: 0
+            }
+        );
+        let result = local_levenshtein_match("A long sentence", "");
+        assert_eq!(
+            result,
+            LocalMatch {
+                distance: 15,
+                start: 0,
+                end: 0
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let result = local_levenshtein_match("lung", "A long sentence");
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!(json, r#"{"distance":1,"start":2,"end":6}"#);
+        assert_eq!(serde_json::from_str::<LocalMatch>(&json).unwrap(), result);
+    }
+
+    #[test]
+    fn match_coverage() {
+        let target = "A long sentence";
+        let result = local_levenshtein_match("long", target);
+        assert_eq!(result.coverage(target), 4.0 / 15.0);
+        // Counted in characters, not bytes
+        let target = "El delfín español";
+        let result = local_levenshtein_match("delfin", target);
+        assert_eq!(&target[result.start..result.end], "delfín");
+        assert_eq!(result.coverage(target), 6.0 / 17.0);
+        let result = local_levenshtein_match("rust", "");
+        assert_eq!(result.coverage(""), 1.0);
+    }
+}
@@ -0,0 +1,127 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+/// A language whose rules for ignoring case differ from the default Unicode rules, for use with
+/// [levenshtein_distance_ignore_case_locale].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Locale {
+    /// The default Unicode lowercasing of [str::to_lowercase], which is correct for most
+    /// languages, including English and the other languages written in the Latin alphabet
+    /// without a dotless i.
+    #[default]
+    Root,
+    /// Turkish, and Azerbaijani which shares its rules, where dotted and dotless i are separate
+    /// letters. Uppercase dotless I ('I', U+0049) lowercases to dotless ı (U+0131), and uppercase
+    /// dotted İ (U+0130) lowercases to dotted i (U+0069). Every other character lowercases as in
+    /// [Locale::Root].
+    Turkish,
+}
+
+impl Locale {
+    /// Lowercases the string following the rules of this locale, only allocating a new string if
+    /// any of its characters change.
+    fn to_lowercase(self, string: &str) -> Cow<'_, str> {
+        match self {
+            Locale::Root => crate::case::to_lowercase(string),
+            // I and İ are uppercase, so a string that is already lowercase doesn't contain them
+            Locale::Turkish if string.chars().all(crate::case::lowercases_to_itself) => {
+                Cow::Borrowed(string)
+            }
+            Locale::Turkish => {
+                // Replace I and İ before lowercasing the rest of the string so that context
+                // sensitive rules such as final sigma still apply as in Locale::Root
+                let replaced = string
+                    .chars()
+                    .map(|character| match character {
+                        'I' => 'ı',
+                        'İ' => 'i',
+                        _ => character,
+                    })
+                    .collect::<String>();
+                Cow::Owned(replaced.to_lowercase())
+            }
+        }
+    }
+}
+
+/// Returns the minimum number of single character insertions, deletions or substitutions
+/// required to convert the source string to the target string, known as the Levenshtein distance,
+/// ignoring case differences according to the rules of the given locale.
+///
+/// With [Locale::Root] this is the same as
+/// [levenshtein_distance_ignore_case](crate::levenshtein_distance_ignore_case). The default
+/// Unicode lowercasing gets some languages wrong, most famously Turkish, where "I" is the
+/// uppercase of the dotless "ı" rather than of "i", and the uppercase of "i" is the dotted "İ".
+/// See [Locale] for the exact mappings of each supported locale.
+///
+/// ```
+/// use fuzzy_string_distance::{
+///     levenshtein_distance_ignore_case, levenshtein_distance_ignore_case_locale, Locale,
+/// };
+/// // Turkish words for "Istanbul" and "light" in uppercase and lowercase
+/// let turkish = |source, target| {
+///     levenshtein_distance_ignore_case_locale(source, target, Locale::Turkish)
+/// };
+/// assert_eq!(0, turkish("İSTANBUL", "istanbul"));
+/// assert_eq!(0, turkish("IŞIK", "ışık"));
+/// // The default rules lowercase I to i, and İ to i followed by a combining dot
+/// assert_eq!(2, levenshtein_distance_ignore_case("IŞIK", "ışık"));
+/// assert_eq!(1, levenshtein_distance_ignore_case("İSTANBUL", "istanbul"));
+/// ```
+///
+/// See also:
+/// - [Dotted and dotless I](https://en.wikipedia.org/wiki/Dotted_and_dotless_I_in_computing)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_ignore_case_locale(
+    source: &str,
+    target: &str,
+    locale: Locale,
+) -> usize {
+    crate::levenshtein_distance(&locale.to_lowercase(source), &locale.to_lowercase(target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turkish_i() {
+        let pairs = [
+            ("İSTANBUL", "istanbul"),
+            ("IŞIK", "ışık"),
+            ("DİKİŞ", "dikiş"),
+        ];
+        for (source, target) in pairs {
+            let result = levenshtein_distance_ignore_case_locale(source, target, Locale::Turkish);
+            assert_eq!(result, 0, "{source}");
+        }
+        // Other letters lowercase as in the root locale, including final sigma
+        let result = levenshtein_distance_ignore_case_locale("ΟΔΟΣ", "οδος", Locale::Turkish);
+        assert_eq!(
+            result,
+            levenshtein_distance_ignore_case_locale("ΟΔΟΣ", "οδος", Locale::Root)
+        );
+        assert_eq!(result, 0);
+        // Dotted and dotless i are different letters in Turkish
+        let result = levenshtein_distance_ignore_case_locale("SIR", "sir", Locale::Turkish);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn root_locale() {
+        let pairs = [("SIR", "sir"), ("IŞIK", "ışık"), ("CAFÉ", "café")];
+        for (source, target) in pairs {
+            let result = levenshtein_distance_ignore_case_locale(source, target, Locale::Root);
+            assert_eq!(
+                result,
+                crate::levenshtein_distance_ignore_case(source, target)
+            );
+        }
+        let result = levenshtein_distance_ignore_case_locale("SIR", "sir", Locale::Root);
+        assert_eq!(result, 0);
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The number of rows of the edit distance matrix handled by each block of bits.
+const BLOCK_BITS: usize = u64::BITS as usize;
+
+/// Returns the minimum number of single character insertions, deletions or substitutions
+/// required to convert the source string to the target string, known as the Levenshtein
+/// distance, using Myers' bit-parallel algorithm.
+///
+/// This returns the same result as [levenshtein_distance](crate::levenshtein_distance), but
+/// instead of calculating each entry in the matrix of edit distances one at a time, it packs a
+/// whole column of 64 entries into the bits of a `u64` and updates them all at once with a
+/// handful of bitwise operations. For long strings this is many times faster than the usual
+/// dynamic programming approach. Strings longer than 64 characters are split into blocks of 64
+/// characters which are updated one after another.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, levenshtein_distance_myers};
+/// assert_eq!(3, levenshtein_distance_myers("kitten", "sitting"));
+/// let source = "the quick brown fox jumps over the lazy dog ".repeat(10);
+/// let target = "the quick brown cat jumps over the lazy frog ".repeat(10);
+/// assert_eq!(
+///     levenshtein_distance(&source, &target),
+///     levenshtein_distance_myers(&source, &target),
+/// );
+/// ```
+///
+/// See also:
+/// - [A fast bit-vector algorithm for approximate string matching based on dynamic programming](https://doi.org/10.1145/316542.316550)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_myers(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    // The bit vectors are sized by the pattern, so we use the shorter input as the pattern to
+    // keep the number of blocks as small as possible. Levenshtein distance is symmetric, so
+    // this doesn't change the result.
+    let (pattern, text) = if source.len() < target.len() {
+        (source, target)
+    } else {
+        (target, source)
+    };
+    if pattern.is_empty() {
+        return text.len();
+    }
+
+    let blocks = pattern.len().div_ceil(BLOCK_BITS);
+    // For each character in the pattern, a bit mask of the rows where that character appears
+    // in the pattern. Characters that aren't in the pattern never match, so have no entry.
+    let mut matches: BTreeMap<char, Vec<u64>> = BTreeMap::new();
+    for (i, &character) in pattern.iter().enumerate() {
+        let masks = matches.entry(character).or_insert_with(|| vec![0; blocks]);
+        masks[i / BLOCK_BITS] |= 1 << (i % BLOCK_BITS);
+    }
+
+    // Rather than the edit distances themselves, we store the differences between each entry
+    // and the one above it in the current column, as a bit vector of the rows where the
+    // difference is +1 and a bit vector of the rows where it is -1. The first column is
+    // converting prefixes of the pattern to an empty string, which goes up by one deletion on
+    // every row.
+    let mut positive = vec![u64::MAX; blocks];
+    let mut negative = vec![0; blocks];
+    // The bit of the last row of the pattern in the last block, where we track the distance
+    let last_row = 1 << ((pattern.len() - 1) % BLOCK_BITS);
+    let mut distance = pattern.len();
+
+    let no_matches = vec![0; blocks];
+    for character in &text {
+        let masks = matches.get(character).unwrap_or(&no_matches);
+        // The first row is converting an empty pattern to prefixes of the text, which goes up
+        // by one insertion on every column, so the difference coming into the top of the
+        // first block is always +1
+        let mut carry = 1;
+        for block in 0..blocks {
+            let high_bit = if block == blocks - 1 {
+                last_row
+            } else {
+                1 << (BLOCK_BITS - 1)
+            };
+            carry = advance_block(
+                &mut positive[block],
+                &mut negative[block],
+                masks[block],
+                carry,
+                high_bit,
+            );
+        }
+        // The carry out of the last block is the horizontal difference of the last row, which
+        // is the change in the distance from the whole pattern to this prefix of the text
+        distance = distance.wrapping_add_signed(carry);
+    }
+    distance
+}
+
+/// Advances one block of the column of vertical differences by one character of the text,
+/// given the horizontal difference coming into the top of the block, and returns the
+/// horizontal difference coming out of the row at `high_bit`.
+fn advance_block(
+    positive: &mut u64,
+    negative: &mut u64,
+    mut matches: u64,
+    carry: isize,
+    high_bit: u64,
+) -> isize {
+    let carry_negative = u64::from(carry < 0);
+    let carry_positive = u64::from(carry > 0);
+    // The rows where the entry is the same as the one diagonally above and to the left, either
+    // because the characters match or because the entry above it was smaller
+    let vertical = matches | *negative;
+    // A -1 coming into the top of the block acts like a match in the first row
+    matches |= carry_negative;
+    // The rows where the entry is the same as the one diagonally above and to the left in the
+    // previous column. The addition propagates runs of matches down the column, which is what
+    // lets us update every row at once.
+    let horizontal = (((matches & *positive).wrapping_add(*positive)) ^ *positive) | matches;
+    // The horizontal differences between this column and the previous column
+    let mut horizontal_positive = *negative | !(horizontal | *positive);
+    let mut horizontal_negative = *positive & horizontal;
+
+    let carry_out = if horizontal_positive & high_bit != 0 {
+        1
+    } else if horizontal_negative & high_bit != 0 {
+        -1
+    } else {
+        0
+    };
+
+    // Shift the horizontal differences down a row so each row lines up with the row below it,
+    // filling in the top row with the difference coming into the block, then work out the new
+    // vertical differences from them
+    horizontal_positive = (horizontal_positive << 1) | carry_positive;
+    horizontal_negative = (horizontal_negative << 1) | carry_negative;
+    *positive = horizontal_negative | !(vertical | horizontal_positive);
+    *negative = horizontal_positive & vertical;
+    carry_out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein_distance;
+
+    /// Generates a pseudo random string from a small alphabet so that random pairs share
+    /// plenty of characters.
+    fn random_string(state: &mut u32, max_length: u32) -> String {
+        let alphabet = ['a', 'b', 'c', 'd', 'é', 'ß'];
+        let mut next = || {
+            *state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            *state >> 16
+        };
+        let length = next() % (max_length + 1);
+        (0..length)
+            .map(|_| alphabet[next() as usize % alphabet.len()])
+            .collect()
+    }
+
+    #[test]
+    fn short_strings() {
+        let result = levenshtein_distance_myers("kitten", "sitting");
+        assert_eq!(result, 3);
+        let result = levenshtein_distance_myers("", "rust");
+        assert_eq!(result, 4);
+        let result = levenshtein_distance_myers("bug", "");
+        assert_eq!(result, 3);
+        let result = levenshtein_distance_myers("El delfín español", "Dolphin");
+        assert_eq!(result, 15);
+    }
+
+    #[test]
+    fn block_boundaries() {
+        for length in [63, 64, 65, 127, 128, 129] {
+            let source = "a".repeat(length);
+            let target = "b".repeat(length);
+            assert_eq!(levenshtein_distance_myers(&source, &target), length);
+            assert_eq!(levenshtein_distance_myers(&source, &source), 0);
+            let longer = format!("{source}b");
+            assert_eq!(levenshtein_distance_myers(&source, &longer), 1);
+        }
+    }
+
+    #[test]
+    fn same_as_levenshtein_distance() {
+        let mut state = 1;
+        for max_length in [8, 70, 200] {
+            for _ in 0..2000 {
+                let source = random_string(&mut state, max_length);
+                let target = random_string(&mut state, max_length);
+                assert_eq!(
+                    levenshtein_distance_myers(&source, &target),
+                    levenshtein_distance(&source, &target),
+                    "{source:?} to {target:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+
+/// Returns the Jaccard index of the sets of character n-grams in the source and target strings,
+/// a score between 0.0 and 1.0 where 1.0 means the strings have exactly the same n-grams and
+/// 0.0 means they have none in common.
+///
+/// An n-gram is a run of `n` consecutive characters, so the bigrams (n = 2) of "night" are
+/// "ni", "ig", "gh" and "ht". The Jaccard index is the number of n-grams in both strings divided
+/// by the number of n-grams in either string. Comparing sets of n-grams is much cheaper than
+/// computing an edit distance, which makes it useful for quickly narrowing down candidates from
+/// a large vocabulary.
+///
+/// ```
+/// use fuzzy_string_distance::ngram_jaccard_similarity;
+/// // night and nacht only share the bigram "ht" out of 7 different bigrams
+/// assert_eq!(1.0 / 7.0, ngram_jaccard_similarity("night", "nacht", 2));
+/// assert_eq!(1.0, ngram_jaccard_similarity("night", "night", 2));
+/// ```
+///
+/// The strings are not padded at the start or end, so the characters at the boundaries appear
+/// in fewer n-grams than the characters in the middle, and a string with fewer than `n`
+/// characters has no n-grams at all. If neither string has any n-grams, the similarity is 1.0
+/// if the strings are equal and 0.0 otherwise.
+///
+/// See also:
+/// - [Jaccard index](https://en.wikipedia.org/wiki/Jaccard_index)
+/// - [N-gram](https://en.wikipedia.org/wiki/N-gram)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+///
+/// # Panics
+///
+/// If `n` is 0.
+pub fn ngram_jaccard_similarity(source: &str, target: &str, n: usize) -> f64 {
+    assert!(n > 0, "n-grams must be at least one character long");
+    let source_chars = source.chars().collect::<Vec<char>>();
+    let target_chars = target.chars().collect::<Vec<char>>();
+    let source_ngrams = source_chars.windows(n).collect::<BTreeSet<&[char]>>();
+    let target_ngrams = target_chars.windows(n).collect::<BTreeSet<&[char]>>();
+    let union = source_ngrams.union(&target_ngrams).count();
+    if union == 0 {
+        return if source == target { 1.0 } else { 0.0 };
+    }
+    let intersection = source_ngrams.intersection(&target_ngrams).count();
+    intersection as f64 / union as f64
+}
+
+/// Returns the Sørensen-Dice coefficient of the character bigrams in the source and target
+/// strings, a score between 0.0 and 1.0 where 1.0 means the strings have exactly the same
+/// bigrams and 0.0 means they have none in common.
+///
+/// A bigram is a pair of consecutive characters, so the bigrams of "night" are "ni", "ig", "gh"
+/// and "ht". The coefficient is twice the number of bigrams in both strings divided by the total
+/// number of bigrams in each string. Bigrams that appear more than once are counted as many
+/// times as they appear in both strings, so "aaaa" and "aa" only have one "aa" bigram in common.
+///
+/// ```
+/// use fuzzy_string_distance::dice_coefficient;
+/// // night and nacht only share the bigram "ht" out of 4 bigrams each
+/// assert_eq!(0.25, dice_coefficient("night", "nacht"));
+/// assert_eq!(1.0, dice_coefficient("night", "night"));
+/// ```
+///
+/// As with [ngram_jaccard_similarity], the strings are not padded, so a string with fewer than
+/// 2 characters has no bigrams. If neither string has any bigrams, the coefficient is 1.0 if the
+/// strings are equal and 0.0 otherwise.
+///
+/// See also:
+/// - [Sørensen-Dice coefficient](https://en.wikipedia.org/wiki/S%C3%B8rensen%E2%80%93Dice_coefficient)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn dice_coefficient(source: &str, target: &str) -> f64 {
+    let source_chars = source.chars().collect::<Vec<char>>();
+    let target_chars = target.chars().collect::<Vec<char>>();
+    let source_bigrams = source_chars.windows(2);
+    let target_bigrams = target_chars.windows(2);
+    let total = source_bigrams.len() + target_bigrams.len();
+    if total == 0 {
+        return if source == target { 1.0 } else { 0.0 };
+    }
+
+    // Count how many times each bigram appears in the source, then use up those counts as we
+    // find the same bigrams in the target so repeated bigrams are only matched as many times
+    // as they appear in both
+    let mut source_counts: BTreeMap<&[char], usize> = BTreeMap::new();
+    for bigram in source_bigrams {
+        *source_counts.entry(bigram).or_insert(0) += 1;
+    }
+    let mut intersection = 0;
+    for bigram in target_bigrams {
+        if let Some(count) = source_counts.get_mut(bigram) {
+            if *count > 0 {
+                *count -= 1;
+                intersection += 1;
+            }
+        }
+    }
+
+    (2 * intersection) as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bigrams() {
+        let result = ngram_jaccard_similarity("night", "nacht", 2);
+        assert_eq!(result, 1.0 / 7.0);
+        let result = ngram_jaccard_similarity("night", "night", 2);
+        assert_eq!(result, 1.0);
+        let result = ngram_jaccard_similarity("night", "day", 2);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn trigrams() {
+        // ban, ana, nan in banana, ban, and in band
+        let result = ngram_jaccard_similarity("banana", "band", 3);
+        assert_eq!(result, 1.0 / 4.0);
+    }
+
+    #[test]
+    fn short_strings() {
+        let result = ngram_jaccard_similarity("a", "a", 2);
+        assert_eq!(result, 1.0);
+        let result = ngram_jaccard_similarity("a", "b", 2);
+        assert_eq!(result, 0.0);
+        let result = ngram_jaccard_similarity("", "", 2);
+        assert_eq!(result, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_length_ngrams() {
+        ngram_jaccard_similarity("night", "nacht", 0);
+    }
+
+    #[test]
+    fn dice() {
+        let result = dice_coefficient("night", "nacht");
+        assert_eq!(result, 0.25);
+        let result = dice_coefficient("night", "night");
+        assert_eq!(result, 1.0);
+        let result = dice_coefficient("night", "day");
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn dice_repeated_bigrams() {
+        // aaaa has the bigram aa three times, but aa only has it once
+        let result = dice_coefficient("aaaa", "aa");
+        assert_eq!(result, 0.5);
+    }
+
+    #[test]
+    fn dice_short_strings() {
+        let result = dice_coefficient("a", "a");
+        assert_eq!(result, 1.0);
+        let result = dice_coefficient("a", "b");
+        assert_eq!(result, 0.0);
+    }
+}
@@ -0,0 +1,173 @@
+use alloc::string::String;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// A Unicode normalization form, which decides how characters that can be written in more than
+/// one way are converted to a single representation before comparing strings.
+///
+/// For instance "é" can be a single precomposed character, or an "e" followed by a combining
+/// acute accent. These look identical but are different sequences of unicode scalar values, so
+/// without normalizing they would be at least one edit apart.
+///
+/// This is only available with the `unicode-normalization` feature enabled.
+///
+/// See also:
+/// - [Unicode equivalence](https://en.wikipedia.org/wiki/Unicode_equivalence)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Normalization {
+    /// Canonical decomposition followed by canonical composition, which combines characters
+    /// with their accents wherever a precomposed character exists.
+    Nfc,
+    /// Canonical decomposition, which splits precomposed characters into a base character
+    /// followed by combining accents.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition, which is like [Nfc](Normalization::Nfc)
+    /// but also replaces compatibility characters such as ligatures with their plain
+    /// equivalents.
+    Nfkc,
+    /// Compatibility decomposition, which is like [Nfd](Normalization::Nfd) but also replaces
+    /// compatibility characters such as ligatures with their plain equivalents.
+    Nfkd,
+}
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string to the
+/// target string after normalizing both of them to the given Unicode normalization form.
+///
+/// Without normalizing, a precomposed "é" and an "e" followed by a combining acute accent are
+/// different unicode scalar values, so strings that look identical can be one or more edits
+/// apart.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, levenshtein_distance_normalized, Normalization};
+/// let precomposed = "caf\u{e9}";
+/// let decomposed = "cafe\u{301}";
+/// assert_eq!(2, levenshtein_distance(precomposed, decomposed));
+/// assert_eq!(0, levenshtein_distance_normalized(precomposed, decomposed, Normalization::Nfc));
+/// ```
+///
+/// The distance is counted in the characters of the normalized strings, so with a decomposed
+/// form such as [Nfd](Normalization::Nfd) an accented character can count as more than one
+/// character.
+///
+/// This is only available with the `unicode-normalization` feature enabled.
+///
+/// See also:
+/// - [Unicode equivalence](https://en.wikipedia.org/wiki/Unicode_equivalence)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_normalized(source: &str, target: &str, form: Normalization) -> usize {
+    crate::levenshtein_distance(&normalize(source, form), &normalize(target, form))
+}
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string to the
+/// target string ignoring any diacritics, such as accents, on their characters.
+///
+/// Both strings are decomposed to [Nfd](Normalization::Nfd), which splits accented characters
+/// into a base character followed by combining marks, and then the combining marks are dropped
+/// before comparing. This is useful for search boxes where users often type without accents.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_ignore_diacritics;
+/// assert_eq!(0, levenshtein_distance_ignore_diacritics("resume", "résumé"));
+/// assert_eq!(0, levenshtein_distance_ignore_diacritics("naïve", "naive"));
+/// // The base letters are still compared as normal
+/// assert_eq!(1, levenshtein_distance_ignore_diacritics("naïve", "native"));
+/// ```
+///
+/// Only characters that decompose into a base character and combining marks lose their
+/// diacritics, so characters such as "ø" or "ł" which have no decomposition are kept as they
+/// are.
+///
+/// This is only available with the `unicode-normalization` feature enabled.
+///
+/// See also:
+/// - [Diacritic](https://en.wikipedia.org/wiki/Diacritic)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_ignore_diacritics(source: &str, target: &str) -> usize {
+    crate::levenshtein_distance(&strip_diacritics(source), &strip_diacritics(target))
+}
+
+/// Decomposes the string and drops all of its combining marks.
+fn strip_diacritics(string: &str) -> String {
+    string
+        .nfd()
+        .filter(|&character| !is_combining_mark(character))
+        .collect()
+}
+
+/// Normalizes the string to the given form.
+pub(crate) fn normalize(string: &str, form: Normalization) -> String {
+    match form {
+        Normalization::Nfc => string.nfc().collect(),
+        Normalization::Nfd => string.nfd().collect(),
+        Normalization::Nfkc => string.nfkc().collect(),
+        Normalization::Nfkd => string.nfkd().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precomposed_and_decomposed() {
+        let precomposed = "caf\u{e9}";
+        let decomposed = "cafe\u{301}";
+        assert_eq!(crate::levenshtein_distance(precomposed, decomposed), 2);
+        for form in [Normalization::Nfc, Normalization::Nfd] {
+            let result = levenshtein_distance_normalized(precomposed, decomposed, form);
+            assert_eq!(result, 0);
+        }
+    }
+
+    #[test]
+    fn decomposed_lengths() {
+        // café to cafe is one substitution when composed, but one deletion of the accent when
+        // decomposed
+        let result = levenshtein_distance_normalized("caf\u{e9}", "cafe", Normalization::Nfc);
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_normalized("caf\u{e9}", "cafe", Normalization::Nfd);
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_normalized("caf\u{e9}", "", Normalization::Nfd);
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn compatibility_forms() {
+        // The fi ligature is only replaced by f and i in the compatibility forms
+        let result = levenshtein_distance_normalized("\u{fb01}ne", "fine", Normalization::Nfc);
+        assert_eq!(result, 2);
+        let result = levenshtein_distance_normalized("\u{fb01}ne", "fine", Normalization::Nfkc);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn ignoring_diacritics() {
+        let result = levenshtein_distance_ignore_diacritics("resume", "résumé");
+        assert_eq!(result, 0);
+        // Decomposed accents are dropped too
+        let result = levenshtein_distance_ignore_diacritics("resume", "re\u{301}sume\u{301}");
+        assert_eq!(result, 0);
+        let result =
+            levenshtein_distance_ignore_diacritics("El delfín español", "El delfin espanol");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn base_letters_preserved() {
+        let result = levenshtein_distance_ignore_diacritics("naive", "native");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_ignore_diacritics("résumé", "rasumo");
+        assert_eq!(result, 2);
+        // ø has no decomposition, so is still different from o
+        let result = levenshtein_distance_ignore_diacritics("søren", "soren");
+        assert_eq!(result, 1);
+    }
+}
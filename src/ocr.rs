@@ -0,0 +1,361 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The cost of substituting glyphs that OCR commonly confuses in [levenshtein_distance_ocr].
+const OCR_COST: f64 = 0.5;
+
+/// The glyphs which OCR most commonly mistakes for one another, in both directions, as the
+/// last character of the source sequence, the source sequence, and the target sequence.
+///
+/// The entries are sorted by their last source character, so [levenshtein_distance_ocr] can
+/// binary search for the confusions that could end at each character of the source.
+const OCR_CONFUSIONS: [(char, &[char], &[char]); 36] = [
+    ('0', &['0'], &['D']),
+    ('0', &['0'], &['O']),
+    ('0', &['0'], &['o']),
+    ('1', &['1'], &['I']),
+    ('1', &['1'], &['l']),
+    ('1', &['1'], &['|']),
+    ('2', &['2'], &['Z']),
+    ('5', &['5'], &['S']),
+    ('6', &['6'], &['G']),
+    ('8', &['8'], &['B']),
+    ('9', &['9'], &['g']),
+    ('B', &['B'], &['8']),
+    ('D', &['D'], &['0']),
+    ('G', &['G'], &['6']),
+    ('I', &['I'], &['1']),
+    ('I', &['I'], &['l']),
+    ('O', &['O'], &['0']),
+    ('O', &['O'], &['o']),
+    ('S', &['S'], &['5']),
+    ('Z', &['Z'], &['2']),
+    ('d', &['d'], &['c', 'l']),
+    ('g', &['g'], &['9']),
+    ('i', &['i', 'i'], &['u']),
+    ('l', &['c', 'l'], &['d']),
+    ('l', &['l'], &['1']),
+    ('l', &['l'], &['I']),
+    ('l', &['l'], &['|']),
+    ('m', &['m'], &['r', 'n']),
+    ('n', &['r', 'n'], &['m']),
+    ('o', &['o'], &['0']),
+    ('o', &['o'], &['O']),
+    ('u', &['u'], &['i', 'i']),
+    ('v', &['v', 'v'], &['w']),
+    ('w', &['w'], &['v', 'v']),
+    ('|', &['|'], &['1']),
+    ('|', &['|'], &['l']),
+];
+
+/// The longest source sequence in [OCR_CONFUSIONS].
+const OCR_LONGEST: usize = 2;
+
+/// A source sequence of characters, the target sequence it can be substituted for, and the cost
+/// of doing so.
+type Confusion = (Vec<char>, Vec<char>, f64);
+
+/// A set of pairs of glyph sequences which are easily confused with each other, and the cost of
+/// substituting one for the other, for use with [levenshtein_distance_confusable].
+///
+/// Each sequence can be more than one character, so that confusions such as "rn" for "m" can be
+/// corrected with a single substitution. Confusions are symmetric, so inserting the pair
+/// ("rn", "m") also allows substituting "m" for "rn" at the same cost.
+///
+/// Insertions, deletions and ordinary substitutions cost 1.0, so a confusion should usually
+/// cost less than 1.0 to make it cheaper than an ordinary substitution, or 0.0 to ignore it
+/// entirely.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance_confusable, ConfusionMap};
+/// let mut confusions = ConfusionMap::ocr();
+/// confusions.insert("ſ", "f", 0.5);
+/// assert_eq!(0.5, levenshtein_distance_confusable("ſast", "fast", &confusions));
+/// assert_eq!(0.5, levenshtein_distance_confusable("rnore", "more", &confusions));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfusionMap {
+    /// The target sequence and cost of substituting each source sequence, keyed by the last
+    /// character of the source sequence, with both orders of every pair present
+    confusions: BTreeMap<char, Vec<Confusion>>,
+    /// The number of characters in the longest source sequence
+    longest: usize,
+}
+
+impl ConfusionMap {
+    /// Creates a new empty ConfusionMap, with which
+    /// [levenshtein_distance_confusable] treats every substitution as an ordinary one.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a ConfusionMap of the glyphs which OCR commonly mistakes for one another, such as
+    /// "0" and "O", "1" and "l", or "rn" and "m", each with a cost of 0.5.
+    ///
+    /// These are the confusions used by [levenshtein_distance_ocr].
+    pub fn ocr() -> Self {
+        let mut confusions = Self::new();
+        for (_, from, to) in OCR_CONFUSIONS {
+            confusions.insert_directed(from.to_vec(), to.to_vec(), OCR_COST);
+        }
+        confusions
+    }
+
+    /// Sets the cost of substituting `a` for `b` and `b` for `a`, replacing any cost already
+    /// set for this pair.
+    ///
+    /// # Panics
+    ///
+    /// If either sequence is empty, as these would be insertions or deletions rather than
+    /// substitutions, or if the cost is negative.
+    pub fn insert(&mut self, a: &str, b: &str, cost: f64) {
+        let a = a.chars().collect::<Vec<char>>();
+        let b = b.chars().collect::<Vec<char>>();
+        assert!(
+            !a.is_empty() && !b.is_empty(),
+            "confused sequences must not be empty"
+        );
+        assert!(cost >= 0.0, "confusion cost must not be negative");
+        self.insert_directed(a.clone(), b.clone(), cost);
+        self.insert_directed(b, a, cost);
+    }
+
+    /// Sets the cost of substituting `from` for `to` only, which must not be empty.
+    fn insert_directed(&mut self, from: Vec<char>, to: Vec<char>, cost: f64) {
+        self.longest = core::cmp::max(self.longest, from.len());
+        let confusions = self.confusions.entry(from[from.len() - 1]).or_default();
+        match confusions
+            .iter_mut()
+            .find(|(source, target, _)| *source == from && *target == to)
+        {
+            Some(confusion) => confusion.2 = cost,
+            None => confusions.push((from, to, cost)),
+        }
+    }
+
+    /// Returns the cost of substituting `a` for `b`, if they are a confusable pair.
+    pub fn get(&self, a: &str, b: &str) -> Option<f64> {
+        let a = a.chars().collect::<Vec<char>>();
+        let b = b.chars().collect::<Vec<char>>();
+        self.confusions
+            .get(a.last()?)?
+            .iter()
+            .find(|(source, target, _)| *source == a && *target == b)
+            .map(|&(_, _, cost)| cost)
+    }
+
+    /// Returns the number of confusable pairs, counting each pair once regardless of order.
+    pub fn len(&self) -> usize {
+        let (total, same) = self
+            .confusions
+            .values()
+            .flatten()
+            .fold((0, 0), |(total, same), (source, target, _)| {
+                (total + 1, same + usize::from(source == target))
+            });
+        (total + same) / 2
+    }
+
+    /// Returns true if there are no confusable pairs.
+    pub fn is_empty(&self) -> bool {
+        self.confusions.is_empty()
+    }
+}
+
+/// Returns the minimum total cost of insertions, deletions or substitutions required to convert
+/// the source string to the target string, where substituting glyphs that OCR commonly
+/// confuses costs less than an ordinary substitution.
+///
+/// Insertions, deletions and ordinary substitutions cost 1.0, as in
+/// [levenshtein_distance](crate::levenshtein_distance), while the confusions of
+/// [ConfusionMap::ocr] such as "0" for "O", "1" for "l" or "rn" for "m" cost 0.5. This makes a
+/// misread word closer to the word it was scanned from than to other words a single edit away,
+/// and text without any confusable glyphs has the usual Levenshtein distance.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_ocr;
+/// assert_eq!(0.5, levenshtein_distance_ocr("0ptimize", "Optimize"));
+/// assert_eq!(1.0, levenshtein_distance_ocr("Xptimize", "Optimize"));
+/// assert_eq!(0.5, levenshtein_distance_ocr("rnodern", "modern"));
+/// assert_eq!(3.0, levenshtein_distance_ocr("kitten", "sitting"));
+/// ```
+///
+/// See also:
+/// - [Optical character recognition](https://en.wikipedia.org/wiki/Optical_character_recognition)
+/// - [levenshtein_distance_confusable] to use your own confusions
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_ocr(source: &str, target: &str) -> f64 {
+    confusable_distance(source, target, OCR_LONGEST, |last| {
+        // The table is sorted by the last source character, so the confusions ending with it
+        // are all next to each other
+        let start = OCR_CONFUSIONS.partition_point(|&(character, _, _)| character < last);
+        let end = OCR_CONFUSIONS.partition_point(|&(character, _, _)| character <= last);
+        OCR_CONFUSIONS[start..end]
+            .iter()
+            .map(|&(_, from, to)| (from, to, OCR_COST))
+    })
+}
+
+/// Returns the minimum total cost of insertions, deletions or substitutions required to convert
+/// the source string to the target string, where substituting one of the pairs of sequences in
+/// the [ConfusionMap] costs the amount given by the map.
+///
+/// Insertions, deletions and substitutions of a character for a different character which
+/// isn't a confusion cost 1.0. With an empty map this is the
+/// [levenshtein_distance](crate::levenshtein_distance).
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance_confusable, ConfusionMap};
+/// let mut confusions = ConfusionMap::new();
+/// confusions.insert("ph", "f", 0.5);
+/// assert_eq!(0.5, levenshtein_distance_confusable("phone", "fone", &confusions));
+/// assert_eq!(2.0, levenshtein_distance_confusable("phone", "fone", &ConfusionMap::new()));
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_confusable(
+    source: &str,
+    target: &str,
+    confusions: &ConfusionMap,
+) -> f64 {
+    confusable_distance(source, target, confusions.longest, |last| {
+        confusions
+            .confusions
+            .get(&last)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|(from, to, cost)| (from.as_slice(), to.as_slice(), *cost))
+    })
+}
+
+/// The implementation of Levenshtein distance with confusions, where `ending_with` returns the
+/// source sequence, target sequence and cost of every confusion whose source sequence ends with
+/// the given character, and `longest` is the length of the longest source sequence.
+fn confusable_distance<'a, F, I>(source: &str, target: &str, longest: usize, ending_with: F) -> f64
+where
+    F: Fn(char) -> I,
+    I: Iterator<Item = (&'a [char], &'a [char], f64)>,
+{
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let target_chars = target.len();
+    // A confusion can reach back as many rows as its source sequence is long, so we keep that
+    // many rows before the current one, reusing them in a ring so row i is at i % rows
+    let rows = core::cmp::max(longest, 1) + 1;
+    let mut distances = vec![vec![0.0; target_chars + 1]; rows];
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j as f64;
+    }
+    for i in 1..=source.len() {
+        let (current, previous) = (i % rows, (i - 1) % rows);
+        distances[current][0] = i as f64;
+        for j in 1..=target_chars {
+            let substitution = if source[i - 1] == target[j - 1] {
+                0.0
+            } else {
+                1.0
+            };
+            let mut distance = (distances[previous][j] + 1.0)
+                .min(distances[current][j - 1] + 1.0)
+                .min(distances[previous][j - 1] + substitution);
+            for (from, to, cost) in ending_with(source[i - 1]) {
+                if source[..i].ends_with(from) && target[..j].ends_with(to) {
+                    let before = distances[(i - from.len()) % rows][j - to.len()];
+                    distance = distance.min(before + cost);
+                }
+            }
+            distances[current][j] = distance;
+        }
+    }
+    distances[source.len() % rows][target_chars]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confused_glyphs_are_cheaper() {
+        let confused = levenshtein_distance_ocr("0ptimize", "Optimize");
+        let unrelated = levenshtein_distance_ocr("Xptimize", "Optimize");
+        assert_eq!(confused, 0.5);
+        assert_eq!(unrelated, 1.0);
+        assert!(confused < unrelated);
+    }
+
+    #[test]
+    fn same_as_levenshtein_without_confusions() {
+        for (source, target) in [("kitten", "sitting"), ("", "rust"), ("bug", "")] {
+            let distance = crate::levenshtein_distance(source, target) as f64;
+            assert_eq!(levenshtein_distance_ocr(source, target), distance);
+            let result = levenshtein_distance_confusable(source, target, &ConfusionMap::new());
+            assert_eq!(result, distance);
+        }
+    }
+
+    #[test]
+    fn multiple_characters() {
+        let result = levenshtein_distance_ocr("rnodern", "modern");
+        assert_eq!(result, 0.5);
+        let result = levenshtein_distance_ocr("modern", "rnodern");
+        assert_eq!(result, 0.5);
+        // Confusions don't chain, so c1 is not a confusion for d even though cl is
+        let result = levenshtein_distance_ocr("clear", "dear");
+        assert_eq!(result, 0.5);
+        let result = levenshtein_distance_ocr("c1ear", "dear");
+        assert_eq!(result, 2.0);
+        // Several confusions in a row, reaching back more than one row
+        let result = levenshtein_distance_ocr("rnrn", "mm");
+        assert_eq!(result, 1.0);
+    }
+
+    #[test]
+    fn custom_confusions() {
+        let mut confusions = ConfusionMap::new();
+        assert!(confusions.is_empty());
+        confusions.insert("ph", "f", 0.5);
+        confusions.insert("f", "ph", 0.0);
+        assert_eq!(confusions.len(), 1);
+        assert_eq!(confusions.get("ph", "f"), Some(0.0));
+        assert_eq!(confusions.get("p", "f"), None);
+        assert_eq!(confusions.get("", "f"), None);
+        let result = levenshtein_distance_confusable("phantom", "fantom", &confusions);
+        assert_eq!(result, 0.0);
+        assert_eq!(ConfusionMap::ocr().len(), OCR_CONFUSIONS.len() / 2);
+    }
+
+    #[test]
+    fn ocr_map_same_as_table() {
+        for (source, target) in [
+            ("0ptimize", "Optimize"),
+            ("rnodern", "modern"),
+            ("c1ear", "dear"),
+        ] {
+            assert_eq!(
+                levenshtein_distance_confusable(source, target, &ConfusionMap::ocr()),
+                levenshtein_distance_ocr(source, target),
+            );
+        }
+        // The table must stay sorted for the binary search
+        assert!(OCR_CONFUSIONS.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert!(OCR_CONFUSIONS
+            .iter()
+            .all(|&(last, from, _)| from.last() == Some(&last)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_confusion() {
+        ConfusionMap::new().insert("", "a", 0.5);
+    }
+}
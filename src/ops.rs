@@ -0,0 +1,669 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A single step in transforming a source string into a target string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EditOp {
+    /// The character is the same in the source and the target, so is kept as is
+    Match(char),
+    /// The character is inserted into the source to match the target
+    Insert(char),
+    /// The character is deleted from the source as it is not in the target
+    Delete(char),
+    /// The character in the source is replaced with a different character in the target
+    Substitute {
+        /// The character in the source
+        from: char,
+        /// The character in the target
+        to: char,
+    },
+}
+
+/// Returns a sequence of single character edits with the minimum number of insertions, deletions
+/// or substitutions required to convert the source string to the target string.
+///
+/// The operations are in order from the start of the source string to the end, and every
+/// character of the source is either matched, deleted, or substituted exactly once, with
+/// insertions placed between them. The number of operations which are not [EditOp::Match] is
+/// the [levenshtein_distance](crate::levenshtein_distance).
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_ops, EditOp};
+/// assert_eq!(
+///     vec![
+///         EditOp::Match('r'),
+///         EditOp::Match('u'),
+///         EditOp::Match('s'),
+///         EditOp::Match('t'),
+///         EditOp::Insert('y'),
+///     ],
+///     levenshtein_ops("rust", "rusty"),
+/// );
+/// ```
+///
+/// Often there are several different sequences of edits with the minimum number of edits.
+/// When following the edits back from the end of both strings, this prefers matches and
+/// substitutions first, then deletions, then insertions.
+///
+/// Unlike [levenshtein_distance](crate::levenshtein_distance), this needs to store the entire
+/// matrix of edit distances, which takes memory proportional to the product of the lengths of
+/// the inputs.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_ops(source: &str, target: &str) -> Vec<EditOp> {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let edit_distances = edit_distance_matrix(&source, &target);
+    backtrace(&source, &target, &edit_distances)
+}
+
+/// Replays the edits on the source string, returning the string they transform it into.
+///
+/// This is the inverse of [levenshtein_ops], so for any source and target,
+/// `apply_ops(source, &levenshtein_ops(source, target)) == target`. The edits don't have to be
+/// the minimum edits, any sequence of edits that consumes the source characters in order can be
+/// applied, which makes the operations usable like a patch.
+///
+/// ```
+/// use fuzzy_string_distance::{apply_ops, levenshtein_ops, EditOp};
+/// let ops = levenshtein_ops("kitten", "sitting");
+/// assert_eq!("sitting", apply_ops("kitten", &ops));
+/// let ops = [EditOp::Match('r'), EditOp::Insert('u'), EditOp::Substitute { from: 's', to: 'n' }];
+/// assert_eq!("run", apply_ops("rs", &ops));
+/// ```
+///
+/// # Panics
+///
+/// If the edits don't line up with the source, which is when a [EditOp::Match],
+/// [EditOp::Delete] or [EditOp::Substitute] refers to a different character than the next
+/// character of the source, when the source runs out of characters before the edits do, or when
+/// there are source characters left over after the last edit.
+pub fn apply_ops(source: &str, ops: &[EditOp]) -> String {
+    let mut source_chars = source.chars();
+    let mut result = String::with_capacity(source.len());
+    // Takes the next character of the source, checking it's the one the edit expects
+    let mut expect = |expected: char| match source_chars.next() {
+        Some(c) if c == expected => (),
+        Some(c) => panic!("Expected {expected:?} in the source but found {c:?}"),
+        None => panic!("Expected {expected:?} in the source but reached the end"),
+    };
+    for op in ops {
+        match *op {
+            EditOp::Match(c) => {
+                expect(c);
+                result.push(c);
+            }
+            EditOp::Insert(c) => result.push(c),
+            EditOp::Delete(c) => expect(c),
+            EditOp::Substitute { from, to } => {
+                expect(from);
+                result.push(to);
+            }
+        }
+    }
+    if let Some(c) = source_chars.next() {
+        panic!("Expected the end of the source but found {c:?}");
+    }
+    result
+}
+
+/// Returns the number of characters which line up between the source string and the target
+/// string, which is the number of [EditOp::Match] edits in the [levenshtein_ops].
+///
+/// ```
+/// use fuzzy_string_distance::matching_characters;
+/// // k and s, and e and i, are substituted, but i, t, t and n all line up
+/// assert_eq!(4, matching_characters("kitten", "sitting"));
+/// assert_eq!(0, matching_characters("abc", "xyz"));
+/// ```
+///
+/// This is not the same as the length of the longer string minus the distance, which is what
+/// [similarity_ratio](crate::similarity_ratio) is based on. The two only agree when the
+/// alignment doesn't both insert and delete characters, otherwise the length minus the distance
+/// is lower by the smaller of the number of insertions and the number of deletions. For
+/// instance "abcd" is converted to "bcda" by deleting the a at the start and inserting one at
+/// the end, so 3 characters line up, but the length of 4 minus the distance of 2 is only 2.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, matching_characters, similarity_ratio};
+/// assert_eq!(3, matching_characters("abcd", "bcda"));
+/// assert_eq!(2, levenshtein_distance("abcd", "bcda"));
+/// assert_eq!(0.5, similarity_ratio("abcd", "bcda"));
+/// ```
+///
+/// When there are several alignments with the minimum number of edits, this counts the matches
+/// in the one chosen by [levenshtein_ops], which isn't necessarily the one with the most
+/// matches. Use [longest_common_subsequence](crate::longest_common_subsequence) to find the most
+/// characters that can line up regardless of the number of edits.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn matching_characters(source: &str, target: &str) -> usize {
+    levenshtein_ops(source, target)
+        .iter()
+        .filter(|op| matches!(op, EditOp::Match(_)))
+        .count()
+}
+
+/// The number of each kind of edit in an optimal alignment of two strings, as returned by
+/// [edit_counts].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EditCounts {
+    /// The number of characters inserted into the source
+    pub insertions: usize,
+    /// The number of characters deleted from the source
+    pub deletions: usize,
+    /// The number of characters in the source replaced with a different character
+    pub substitutions: usize,
+    /// The number of characters which are the same in the source and the target
+    pub matches: usize,
+}
+
+/// Returns how many of each kind of edit there are in the [levenshtein_ops] from the source
+/// string to the target string, along with how many characters are matched.
+///
+/// The insertions, deletions and substitutions always add up to the
+/// [levenshtein_distance](crate::levenshtein_distance), and the deletions, substitutions and
+/// matches add up to the number of characters in the source.
+///
+/// ```
+/// use fuzzy_string_distance::{edit_counts, EditCounts};
+/// assert_eq!(
+///     EditCounts { insertions: 1, deletions: 0, substitutions: 2, matches: 4 },
+///     edit_counts("kitten", "sitting"),
+/// );
+/// ```
+///
+/// When there are several alignments with the minimum number of edits, the counts are from the
+/// one chosen by [levenshtein_ops], which is always the same one for the same inputs. Other
+/// optimal alignments can have a different mix of edits, such as a deletion and an insertion in
+/// place of two substitutions, with the same total.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn edit_counts(source: &str, target: &str) -> EditCounts {
+    let mut counts = EditCounts::default();
+    for op in levenshtein_ops(source, target) {
+        match op {
+            EditOp::Match(_) => counts.matches += 1,
+            EditOp::Insert(_) => counts.insertions += 1,
+            EditOp::Delete(_) => counts.deletions += 1,
+            EditOp::Substitute { .. } => counts.substitutions += 1,
+        }
+    }
+    counts
+}
+
+/// Returns the source and target strings aligned against each other, with gaps where characters
+/// need to be inserted or deleted to convert the source string to the target string.
+///
+/// The two returned vectors are always the same length, and each position holds either the
+/// characters that are matched or substituted for each other, or a character on one side and
+/// `None` on the other where a character is deleted from the source or inserted into the
+/// target. The alignment follows the same edits as [levenshtein_ops].
+///
+/// This is useful for rendering a side by side comparison of two strings:
+///
+/// ```
+/// use fuzzy_string_distance::align;
+/// let (source, target) = align("kitten", "sitting");
+/// let render = |aligned: Vec<Option<char>>| aligned
+///     .into_iter()
+///     .map(|c| c.unwrap_or('-'))
+///     .collect::<String>();
+/// assert_eq!("kitten-", render(source));
+/// assert_eq!("sitting", render(target));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn align(source: &str, target: &str) -> (Vec<Option<char>>, Vec<Option<char>>) {
+    levenshtein_ops(source, target)
+        .into_iter()
+        .map(|op| match op {
+            EditOp::Match(c) => (Some(c), Some(c)),
+            EditOp::Insert(c) => (None, Some(c)),
+            EditOp::Delete(c) => (Some(c), None),
+            EditOp::Substitute { from, to } => (Some(from), Some(to)),
+        })
+        .unzip()
+}
+
+/// Returns a sequence of single character edits with the minimum number of insertions, deletions
+/// or substitutions required to convert the source string to the target string, as per
+/// [levenshtein_ops], using Hirschberg's algorithm so that only linear memory is needed.
+///
+/// [levenshtein_ops] stores the entire matrix of edit distances to follow the edits back from
+/// the end, which takes memory proportional to the product of the lengths of the inputs. This
+/// instead finds where an optimal alignment crosses the middle of the longer string by running
+/// the Levenshtein distance forwards over its first half and backwards over its second half,
+/// only keeping a single row of edit distances sized by the shorter string. Then it splits both
+/// strings at that point and aligns each half the same way. This takes roughly twice as long
+/// as [levenshtein_ops], but memory proportional to the length of the shorter input, besides
+/// the returned edits.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_ops, levenshtein_ops_linear, EditOp};
+/// assert_eq!(
+///     vec![
+///         EditOp::Match('r'),
+///         EditOp::Match('u'),
+///         EditOp::Match('s'),
+///         EditOp::Match('t'),
+///         EditOp::Insert('y'),
+///     ],
+///     levenshtein_ops_linear("rust", "rusty"),
+/// );
+/// ```
+///
+/// Often there are several different sequences of edits with the minimum number of edits, and
+/// this does not always pick the same one as [levenshtein_ops]. Where several points in the
+/// shorter string are on an optimal alignment through the middle of the longer string, the
+/// earliest of them is used to split the strings, and once either half is down to a single
+/// character it is aligned with the same preferences as [levenshtein_ops].
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_ops_linear(source: &str, target: &str) -> Vec<EditOp> {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let mut ops = Vec::with_capacity(core::cmp::max(source.len(), target.len()));
+    // The rows of edit distances are sized by the target, so we make sure the shorter input is
+    // the target. Converting the target to the source uses the same edits in reverse, so we
+    // just need to swap insertions for deletions and the sides of each substitution back.
+    if target.len() > source.len() {
+        hirschberg(&target, &source, &mut ops);
+        for op in &mut ops {
+            *op = match *op {
+                EditOp::Match(c) => EditOp::Match(c),
+                EditOp::Insert(c) => EditOp::Delete(c),
+                EditOp::Delete(c) => EditOp::Insert(c),
+                EditOp::Substitute { from, to } => EditOp::Substitute { from: to, to: from },
+            };
+        }
+    } else {
+        hirschberg(&source, &target, &mut ops);
+    }
+    ops
+}
+
+/// Returns the entire matrix of edit distances between every prefix of the source string and
+/// every prefix of the target string, which [levenshtein_distance](crate::levenshtein_distance)
+/// works through to find the distance.
+///
+/// The matrix has a row for each prefix of the source, from the empty prefix to the whole
+/// string, and a column for each prefix of the target, so the entry at `[i][j]` is the distance
+/// from the first `i` characters of the source to the first `j` characters of the target. The
+/// first row and column count up from 0, and the bottom right entry is the distance between the
+/// whole strings.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_matrix;
+/// let matrix = levenshtein_matrix("cat", "cut");
+/// assert_eq!(
+///     vec![
+///         vec![0, 1, 2, 3],
+///         vec![1, 0, 1, 2],
+///         vec![2, 1, 1, 2],
+///         vec![3, 2, 2, 1],
+///     ],
+///     matrix,
+/// );
+/// ```
+///
+/// This takes memory proportional to the product of the lengths of the inputs, and is intended
+/// for visualising how the distance is calculated, such as for teaching or for debugging an
+/// unexpected distance. Use [levenshtein_distance](crate::levenshtein_distance) when you only
+/// need the distance.
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_matrix(source: &str, target: &str) -> Vec<Vec<usize>> {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    edit_distance_matrix(&source, &target)
+}
+
+/// Appends the edits to convert the source to the target onto `ops`, splitting the source in
+/// half and recursing on each half until the problem is small enough to backtrace directly.
+fn hirschberg(source: &[char], target: &[char], ops: &mut Vec<EditOp>) {
+    if source.is_empty() {
+        ops.extend(target.iter().map(|&c| EditOp::Insert(c)));
+        return;
+    }
+    if target.is_empty() {
+        ops.extend(source.iter().map(|&c| EditOp::Delete(c)));
+        return;
+    }
+    if source.len() == 1 || target.len() == 1 {
+        // The matrix only has two rows or two columns, so storing it is still linear
+        let edit_distances = edit_distance_matrix(source, target);
+        ops.extend(backtrace(source, target, &edit_distances));
+        return;
+    }
+
+    let middle = source.len() / 2;
+    let (first_half, second_half) = source.split_at(middle);
+    // The distances from the first half of the source to every prefix of the target
+    let forwards = last_row(first_half.iter(), target.iter());
+    // The distances from the second half of the source to every suffix of the target, where
+    // the entry at k is for the suffix of length k
+    let backwards = last_row(second_half.iter().rev(), target.iter().rev());
+    // An optimal alignment has to cross from the first half to the second half somewhere, and
+    // the total distance through each column of the target is the distance to reach it
+    // forwards plus the distance from it to the end
+    let split = (0..=target.len())
+        .min_by_key(|&k| forwards[k] + backwards[target.len() - k])
+        .unwrap();
+    let (target_start, target_end) = target.split_at(split);
+    hirschberg(first_half, target_start, ops);
+    hirschberg(second_half, target_end, ops);
+}
+
+/// Computes only the final row of the matrix of Levenshtein distances from the source to each
+/// prefix of the target, taking the characters in the order the iterators give them.
+fn last_row<'a, S, T>(source: S, target: T) -> Vec<usize>
+where
+    S: Iterator<Item = &'a char>,
+    T: Iterator<Item = &'a char> + Clone + ExactSizeIterator,
+{
+    let mut edit_distances = (0..=target.len()).collect::<Vec<usize>>();
+    let mut new_edit_distances = vec![0; target.len() + 1];
+    for (i, source_char) in source.enumerate() {
+        new_edit_distances[0] = i + 1;
+        for (j, target_char) in target.clone().enumerate() {
+            let deletion = edit_distances[j + 1] + 1;
+            let insertion = new_edit_distances[j] + 1;
+            let substitution = if source_char == target_char {
+                edit_distances[j]
+            } else {
+                edit_distances[j] + 1
+            };
+            new_edit_distances[j + 1] =
+                core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+        }
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+    edit_distances
+}
+
+/// Computes the entire matrix A of `source` length + 1 rows and `target` length + 1 columns
+/// of Levenshtein distances between each prefix of `source` and each prefix of `target`.
+pub(crate) fn edit_distance_matrix(source: &[char], target: &[char]) -> Vec<Vec<usize>> {
+    let mut edit_distances = Vec::with_capacity(source.len() + 1);
+    // First row is converting "" to each prefix of target, which is all insertions
+    edit_distances.push((0..=target.len()).collect::<Vec<usize>>());
+    for (i, source_char) in source.iter().enumerate() {
+        let mut row = vec![0; target.len() + 1];
+        // First column is converting each prefix of source to "", which is all deletions
+        row[0] = i + 1;
+        for (j, target_char) in target.iter().enumerate() {
+            let previous_row = &edit_distances[i];
+            let deletion = previous_row[j + 1] + 1;
+            let insertion = row[j] + 1;
+            let substitution = if source_char == target_char {
+                previous_row[j]
+            } else {
+                previous_row[j] + 1
+            };
+            row[j + 1] = core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+        }
+        edit_distances.push(row);
+    }
+    edit_distances
+}
+
+/// Follows a path of minimum edits back from the bottom right of the matrix of edit distances
+/// to the top left, returning the edits along the path in order from the start of the strings.
+fn backtrace(source: &[char], target: &[char], edit_distances: &[Vec<usize>]) -> Vec<EditOp> {
+    let mut ops = Vec::with_capacity(core::cmp::max(source.len(), target.len()));
+    let mut i = source.len();
+    let mut j = target.len();
+    while i > 0 || j > 0 {
+        let distance = edit_distances[i][j];
+        if i > 0 && j > 0 {
+            let from = source[i - 1];
+            let to = target[j - 1];
+            // If we got to A[i, j] from A[i - 1, j - 1] then we either matched or substituted
+            // the last characters of both prefixes
+            if from == to && distance == edit_distances[i - 1][j - 1] {
+                ops.push(EditOp::Match(from));
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+            if from != to && distance == edit_distances[i - 1][j - 1] + 1 {
+                ops.push(EditOp::Substitute { from, to });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        // If we got to A[i, j] from A[i - 1, j] then we deleted the last source character,
+        // otherwise we must have got here from A[i, j - 1] by inserting the last target
+        // character
+        if i > 0 && distance == edit_distances[i - 1][j] + 1 {
+            ops.push(EditOp::Delete(source[i - 1]));
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insert(target[j - 1]));
+            j -= 1;
+        }
+    }
+    ops.reverse();
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein_distance;
+
+    #[test]
+    fn ops_reproduce_target() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("ferrisground", "run"),
+            ("Edit distance", "Eddy"),
+            ("", "rust"),
+            ("bug", ""),
+            ("El delfín español", "Dolphin"),
+            ("🧑‍🔬", "🧑"),
+        ];
+        for (source, target) in pairs {
+            let ops = levenshtein_ops(source, target);
+            assert_eq!(apply_ops(source, &ops), target);
+            let edits = ops
+                .iter()
+                .filter(|op| !matches!(op, EditOp::Match(_)))
+                .count();
+            assert_eq!(edits, levenshtein_distance(source, target));
+        }
+    }
+
+    #[test]
+    fn linear_ops_reproduce_target() {
+        // Pseudo random strings from a small alphabet so that pairs share plenty of characters
+        let mut state: u32 = 7;
+        let mut random_string = || {
+            let mut next = || {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                state >> 16
+            };
+            let length = next() % 40;
+            (0..length)
+                .map(|_| ['a', 'b', 'c', 'é', '🧑'][next() as usize % 5])
+                .collect::<String>()
+        };
+        for _ in 0..2000 {
+            let source = random_string();
+            let target = random_string();
+            let ops = levenshtein_ops_linear(&source, &target);
+            assert_eq!(apply_ops(&source, &ops), target);
+            let edits = ops
+                .iter()
+                .filter(|op| !matches!(op, EditOp::Match(_)))
+                .count();
+            assert_eq!(edits, levenshtein_distance(&source, &target));
+        }
+    }
+
+    #[test]
+    fn linear_ops_small() {
+        let result = levenshtein_ops_linear("kitten", "sitting");
+        assert_eq!(apply_ops("kitten", &result), "sitting");
+        assert_eq!(result.len(), 7);
+        let result = levenshtein_ops_linear("", "ab");
+        assert_eq!(result, vec![EditOp::Insert('a'), EditOp::Insert('b')]);
+        let result = levenshtein_ops_linear("ab", "");
+        assert_eq!(result, vec![EditOp::Delete('a'), EditOp::Delete('b')]);
+    }
+
+    #[test]
+    fn applying_patches() {
+        let ops = [EditOp::Delete('a'), EditOp::Match('b'), EditOp::Insert('é')];
+        assert_eq!(apply_ops("ab", &ops), "bé");
+        assert_eq!(apply_ops("", &[]), "");
+    }
+
+    #[test]
+    #[should_panic]
+    fn applying_mismatched_ops() {
+        apply_ops("ab", &[EditOp::Match('a'), EditOp::Delete('c')]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn applying_too_few_ops() {
+        apply_ops("ab", &[EditOp::Match('a')]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn applying_too_many_ops() {
+        apply_ops("a", &[EditOp::Match('a'), EditOp::Delete('b')]);
+    }
+
+    #[test]
+    fn kitten_sitting_ops() {
+        let result = levenshtein_ops("kitten", "sitting");
+        assert_eq!(
+            result,
+            vec![
+                EditOp::Substitute { from: 'k', to: 's' },
+                EditOp::Match('i'),
+                EditOp::Match('t'),
+                EditOp::Match('t'),
+                EditOp::Substitute { from: 'e', to: 'i' },
+                EditOp::Match('n'),
+                EditOp::Insert('g'),
+            ]
+        );
+    }
+
+    #[test]
+    fn aligning() {
+        let (source, target) = align("ferrisground", "run");
+        assert_eq!(source.len(), target.len());
+        let source = source.into_iter().flatten().collect::<String>();
+        let target = target.into_iter().flatten().collect::<String>();
+        assert_eq!(source, "ferrisground");
+        assert_eq!(target, "run");
+    }
+
+    #[test]
+    fn aligning_empty() {
+        let (source, target) = align("", "rust");
+        assert_eq!(source, vec![None; 4]);
+        assert_eq!(target, vec![Some('r'), Some('u'), Some('s'), Some('t')]);
+    }
+
+    #[test]
+    fn kitten_sitting_matrix() {
+        let matrix = levenshtein_matrix("kitten", "sitting");
+        let expected = vec![
+            vec![0, 1, 2, 3, 4, 5, 6, 7],
+            vec![1, 1, 2, 3, 4, 5, 6, 7],
+            vec![2, 2, 1, 2, 3, 4, 5, 6],
+            vec![3, 3, 2, 1, 2, 3, 4, 5],
+            vec![4, 4, 3, 2, 1, 2, 3, 4],
+            vec![5, 5, 4, 3, 2, 2, 3, 4],
+            vec![6, 6, 5, 4, 3, 3, 2, 3],
+        ];
+        assert_eq!(matrix, expected);
+        assert_eq!(
+            matrix[6][7],
+            crate::levenshtein_distance("kitten", "sitting")
+        );
+    }
+
+    #[test]
+    fn empty_matrix() {
+        assert_eq!(levenshtein_matrix("", ""), vec![vec![0]]);
+        assert_eq!(
+            levenshtein_matrix("ab", ""),
+            vec![vec![0], vec![1], vec![2]]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let ops = levenshtein_ops("kitten", "sitting");
+        let json = serde_json::to_string(&ops).unwrap();
+        let result = serde_json::from_str::<Vec<EditOp>>(&json).unwrap();
+        assert_eq!(result, ops);
+        let json = serde_json::to_string(&EditOp::Substitute { from: 'k', to: 's' }).unwrap();
+        assert_eq!(json, r#"{"Substitute":{"from":"k","to":"s"}}"#);
+    }
+
+    #[test]
+    fn counting_matches() {
+        let result = matching_characters("kitten", "sitting");
+        assert_eq!(result, 4);
+        let result = matching_characters("rust", "rust");
+        assert_eq!(result, 4);
+        let result = matching_characters("abc", "xyz");
+        assert_eq!(result, 0);
+        let result = matching_characters("", "rust");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn counting_edits() {
+        let result = edit_counts("kitten", "sitting");
+        assert_eq!(result.insertions, 1);
+        assert_eq!(result.deletions, 0);
+        assert_eq!(result.substitutions, 2);
+        assert_eq!(result.matches, 4);
+        assert_eq!(
+            result.insertions + result.deletions + result.substitutions,
+            crate::levenshtein_distance("kitten", "sitting")
+        );
+        let result = edit_counts("ferrisground", "run");
+        assert_eq!(result.deletions, 9);
+        assert_eq!(result.matches, 3);
+    }
+
+    #[test]
+    fn counting_empty_edits() {
+        assert_eq!(edit_counts("", ""), EditCounts::default());
+        let result = edit_counts("", "rust");
+        assert_eq!(result.insertions, 4);
+        let result = edit_counts("bug", "");
+        assert_eq!(result.deletions, 3);
+    }
+}
@@ -0,0 +1,105 @@
+use rayon::prelude::*;
+
+use crate::Levenshtein;
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the query to each of the
+/// targets, computing the distances in parallel.
+///
+/// The distances are returned in the same order as the targets. Each thread reuses its own
+/// [Levenshtein] buffers across the targets it works through.
+///
+/// This is only available with the `rayon` feature enabled.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distances_par;
+/// let targets = ["rusty", "crust", "typography", "rust"];
+/// assert_eq!(vec![1, 1, 9, 0], levenshtein_distances_par("rust", &targets));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distances_par(query: &str, targets: &[&str]) -> Vec<usize> {
+    targets
+        .par_iter()
+        .map_init(Levenshtein::new, |levenshtein, target| {
+            levenshtein.distance(query, target)
+        })
+        .collect()
+}
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) between every pair of items,
+/// as per [distance_matrix](crate::distance_matrix), computing the rows of the matrix in
+/// parallel.
+///
+/// This is only available with the `rayon` feature enabled.
+///
+/// ```
+/// use fuzzy_string_distance::{distance_matrix, distance_matrix_par};
+/// let items = ["rust", "trust", "crab"];
+/// assert_eq!(distance_matrix(&items), distance_matrix_par(&items));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn distance_matrix_par(items: &[&str]) -> Vec<Vec<usize>> {
+    // Each row only computes the distances above the diagonal, so later rows have less work,
+    // but rayon splits the rows up adaptively so the threads still stay busy
+    let mut matrix = (0..items.len())
+        .into_par_iter()
+        .map_init(Levenshtein::new, |levenshtein, i| {
+            let mut row = vec![0; items.len()];
+            for (j, target) in items.iter().enumerate().skip(i + 1) {
+                row[j] = levenshtein.distance(items[i], target);
+            }
+            row
+        })
+        .collect::<Vec<Vec<usize>>>();
+    // Mirror the distances above the diagonal into each row from the rows before it
+    for i in 1..matrix.len() {
+        let (above, below) = matrix.split_at_mut(i);
+        for (j, row) in above.iter().enumerate() {
+            below[0][j] = row[i];
+        }
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein_distance;
+
+    #[test]
+    fn same_as_sequential() {
+        let targets = [
+            "kitten",
+            "sitting",
+            "ferrisground",
+            "run",
+            "Edit distance",
+            "Eddy",
+            "unrelated",
+            "SCREAMING",
+            "El delfín español",
+            "Dolphin",
+            "",
+            "🧑‍🔬",
+        ];
+        for query in targets {
+            let sequential = targets
+                .iter()
+                .map(|target| levenshtein_distance(query, target))
+                .collect::<Vec<usize>>();
+            assert_eq!(levenshtein_distances_par(query, &targets), sequential);
+        }
+    }
+
+    #[test]
+    fn matrix_same_as_sequential() {
+        let items = ["kitten", "sitting", "mitten", "", "El delfín español", "🧑‍🔬"];
+        assert_eq!(distance_matrix_par(&items), crate::distance_matrix(&items));
+        assert_eq!(distance_matrix_par(&[]), Vec::<Vec<usize>>::new());
+    }
+}
@@ -0,0 +1,469 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Returns the Soundex code of the string, a letter followed by three digits which is the same
+/// for names that sound alike when spoken in English.
+///
+/// Names such as "Robert" and "Rupert" are several edits apart, but are pronounced similarly,
+/// so have the same Soundex code. The first letter of the string is kept, and the letters after
+/// it are replaced by digits for groups of similar sounding consonants, skipping vowels and
+/// merging repeated sounds, until there are three digits. Shorter codes are padded with zeros.
+///
+/// ```
+/// use fuzzy_string_distance::soundex;
+/// assert_eq!("R163", soundex("Robert"));
+/// assert_eq!("R163", soundex("Rupert"));
+/// assert_eq!("T522", soundex("Tymczak"));
+/// assert_eq!("L000", soundex("Lee"));
+/// ```
+///
+/// Soundex is designed for English names, and only looks at the ASCII letters of the string,
+/// ignoring case. Any other characters, including accented letters, are skipped, so a string
+/// with no ASCII letters has an empty code.
+///
+/// See also:
+/// - [Soundex](https://en.wikipedia.org/wiki/Soundex)
+/// - [soundex_matches]
+pub fn soundex(string: &str) -> String {
+    let mut letters = string
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|letter| letter.to_ascii_uppercase());
+    let Some(first) = letters.next() else {
+        return String::new();
+    };
+    let mut code = String::with_capacity(4);
+    code.push(first);
+    let mut previous = soundex_digit(first);
+    for letter in letters {
+        if code.len() == 4 {
+            break;
+        }
+        // H and W don't separate consonants with the same digit, unlike vowels
+        if letter == 'H' || letter == 'W' {
+            continue;
+        }
+        let digit = soundex_digit(letter);
+        if let Some(digit) = digit {
+            if previous != Some(digit) {
+                code.push(digit);
+            }
+        }
+        previous = digit;
+    }
+    while code.len() < 4 {
+        code.push('0');
+    }
+    code
+}
+
+/// Returns true if the two strings have the same [soundex] code.
+///
+/// ```
+/// use fuzzy_string_distance::soundex_matches;
+/// assert!(soundex_matches("Robert", "Rupert"));
+/// assert!(!soundex_matches("Robert", "Rubin"));
+/// ```
+///
+/// Strings with no ASCII letters have no code, so never match anything.
+pub fn soundex_matches(source: &str, target: &str) -> bool {
+    let source = soundex(source);
+    !source.is_empty() && source == soundex(target)
+}
+
+/// Returns the Soundex digit of an uppercase ASCII letter, or None for vowels, H, W and Y.
+fn soundex_digit(letter: char) -> Option<char> {
+    match letter {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        _ => None,
+    }
+}
+
+/// Returns the Metaphone code of the string, a sequence of letters standing for the sounds of
+/// the string when spoken in English.
+///
+/// Metaphone follows many more of the rules of English spelling than [soundex], such as "PH"
+/// sounding like "F", a "K" before an "N" at the start of a word being silent, or "C" sounding
+/// like "S" before an "E", "I" or "Y". The code uses the consonants which sound different to
+/// each other, "X" for the "SH" sound and "0" for the "TH" sound, except in names like "Thomas"
+/// where the "TH" is a plain "T". Vowels are only kept at the start of the string. Unlike
+/// Soundex, the code is not truncated or padded to a fixed length.
+///
+/// ```
+/// use fuzzy_string_distance::metaphone;
+/// assert_eq!("FLP", metaphone("Philip"));
+/// assert_eq!("FLP", metaphone("Filip"));
+/// assert_eq!("NT", metaphone("Knight"));
+/// assert_eq!("0M", metaphone("Thumb"));
+/// assert_eq!("TMSN", metaphone("Thompson"));
+/// ```
+///
+/// Metaphone is designed for English words, and only looks at the ASCII letters of the string,
+/// ignoring case. Any other characters, including spaces and accented letters, are skipped, so
+/// a string of several words is encoded as if it was one word, and a string with no ASCII
+/// letters has an empty code.
+///
+/// See also:
+/// - [Metaphone](https://en.wikipedia.org/wiki/Metaphone)
+/// - [metaphone_matches]
+pub fn metaphone(string: &str) -> String {
+    let letters = string
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|letter| letter.to_ascii_uppercase())
+        .collect::<Vec<char>>();
+    let letter = |i: usize| letters.get(i).copied();
+    let mut code = String::new();
+    // The first letter of these pairs is silent at the start of a word
+    let start = match letters.as_slice() {
+        ['K', 'N', ..] | ['G', 'N', ..] | ['P', 'N', ..] | ['A', 'E', ..] | ['W', 'R', ..] => 1,
+        _ => 0,
+    };
+    let mut i = start;
+    while i < letters.len() {
+        let current = letters[i];
+        let previous = if i > start { letter(i - 1) } else { None };
+        let next = letter(i + 1);
+        let after = letter(i + 2);
+        // Doubled letters sound the same as a single letter, except for C as in "accept"
+        if previous == Some(current) && current != 'C' {
+            i += 1;
+            continue;
+        }
+        match current {
+            'A' | 'E' | 'I' | 'O' | 'U' => {
+                if i == start {
+                    code.push(current);
+                }
+            }
+            // Silent at the end of a word after M, as in "dumb"
+            'B' => {
+                if !(previous == Some('M') && next.is_none()) {
+                    code.push('B');
+                }
+            }
+            'C' => match (next, after) {
+                (Some('I'), Some('A')) => code.push('X'),
+                (Some('H'), _) if previous == Some('S') => code.push('K'),
+                (Some('H'), _) => code.push('X'),
+                // Silent after S before an E, I or Y, as in "science"
+                (Some('I' | 'E' | 'Y'), _) if previous == Some('S') => (),
+                (Some('I' | 'E' | 'Y'), _) => code.push('S'),
+                _ => code.push('K'),
+            },
+            'D' => match (next, after) {
+                (Some('G'), Some('E' | 'I' | 'Y')) => {
+                    code.push('J');
+                    // The G is part of the J sound, as in "edge"
+                    i += 1;
+                }
+                _ => code.push('T'),
+            },
+            'G' => match (next, after) {
+                // Silent before an H that isn't before a vowel, as in "night"
+                (Some('H'), Some(after)) if !is_vowel(after) => (),
+                // Silent before a final N or NED, as in "sign" or "signed"
+                (Some('N'), None) => (),
+                (Some('N'), _) if letters[i + 1..] == ['N', 'E', 'D'] => (),
+                (Some('E' | 'I' | 'Y'), _) if previous != Some('G') => code.push('J'),
+                _ => code.push('K'),
+            },
+            // Silent after the consonants it changes the sound of, or when not before a vowel
+            'H' => {
+                let changes_previous = matches!(previous, Some('C' | 'G' | 'P' | 'S' | 'T'));
+                if !changes_previous && next.is_some_and(is_vowel) {
+                    code.push('H');
+                }
+            }
+            // Silent after C, as in "back"
+            'K' => {
+                if previous != Some('C') {
+                    code.push('K');
+                }
+            }
+            'P' => {
+                if next == Some('H') {
+                    code.push('F');
+                } else if !(previous == Some('M') && next == Some('S')) {
+                    // Silent between M and S, as in "Thompson"
+                    code.push('P');
+                }
+            }
+            'Q' => code.push('K'),
+            'S' => match (next, after) {
+                (Some('H'), _) | (Some('I'), Some('O' | 'A')) => code.push('X'),
+                _ => code.push('S'),
+            },
+            'T' => match (next, after) {
+                (Some('I'), Some('O' | 'A')) => code.push('X'),
+                // Sounds like a plain T before OM or AM, as in "Thomas" or "Thompson"
+                (Some('H'), Some('O' | 'A')) if letter(i + 3) == Some('M') => code.push('T'),
+                (Some('H'), _) => code.push('0'),
+                // Silent before CH, as in "watch"
+                (Some('C'), Some('H')) => (),
+                _ => code.push('T'),
+            },
+            'V' => code.push('F'),
+            // Only sound like consonants before a vowel, and WH at the start sounds like W
+            'W' | 'Y' => {
+                if next.is_some_and(is_vowel) {
+                    code.push(current);
+                } else if current == 'W' && i == start && next == Some('H') {
+                    code.push('W');
+                    i += 1;
+                }
+            }
+            'X' => {
+                if i == start {
+                    code.push('S');
+                } else {
+                    code.push_str("KS");
+                }
+            }
+            'Z' => code.push('S'),
+            // F, J, L, M, N and R
+            _ => code.push(current),
+        }
+        i += 1;
+    }
+    code
+}
+
+/// Returns true if the two strings have the same [metaphone] code.
+///
+/// ```
+/// use fuzzy_string_distance::metaphone_matches;
+/// assert!(metaphone_matches("Stephen", "Steven"));
+/// assert!(!metaphone_matches("Stephen", "Simon"));
+/// ```
+///
+/// Strings with no ASCII letters have no code, so never match anything.
+pub fn metaphone_matches(source: &str, target: &str) -> bool {
+    let source = metaphone(source);
+    !source.is_empty() && source == metaphone(target)
+}
+
+/// How much each part of [phonetic_similarity_with] contributes to the score.
+///
+/// The default weights are 0.3 for [metaphone], 0.2 for [soundex] and 0.5 for the
+/// [similarity_ratio](crate::similarity_ratio), so strings which sound alike score at least
+/// 0.5 however they are spelt, and the edit distance decides the rest.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhoneticWeights {
+    /// The weight given to the strings having the same [metaphone] code
+    pub metaphone: f64,
+    /// The weight given to the strings having the same [soundex] code
+    pub soundex: f64,
+    /// The weight given to the [similarity_ratio](crate::similarity_ratio) of the strings
+    pub edit: f64,
+}
+
+impl Default for PhoneticWeights {
+    fn default() -> Self {
+        PhoneticWeights {
+            metaphone: 0.3,
+            soundex: 0.2,
+            edit: 0.5,
+        }
+    }
+}
+
+/// Returns a similarity score between 0.0 and 1.0 for the source and target strings, which
+/// combines whether they sound alike with how many edits apart they are, using the default
+/// [PhoneticWeights].
+///
+/// Names such as "Stephen" and "Steven" are spelt quite differently, but sound the same, so
+/// score much higher than their [similarity_ratio](crate::similarity_ratio) alone. See
+/// [phonetic_similarity_with] for how the score is calculated.
+///
+/// ```
+/// use fuzzy_string_distance::{phonetic_similarity, similarity_ratio};
+/// assert!(phonetic_similarity("Stephen", "Steven") > 0.8);
+/// assert!(similarity_ratio("Stephen", "Steven") < 0.8);
+/// assert!(phonetic_similarity("Stephen", "Simon") < 0.5);
+/// ```
+///
+/// Note, the edit distance part of the score compares strings on a unicode scalar value basis,
+/// as per [str::chars]. While this comparison is less likely to cut a 'character' in two than
+/// a byte by byte basis, it still does not compare grapheme clusters.
+pub fn phonetic_similarity(source: &str, target: &str) -> f64 {
+    phonetic_similarity_with(source, target, PhoneticWeights::default())
+}
+
+/// Returns a similarity score between 0.0 and 1.0 for the source and target strings, which
+/// combines whether they sound alike with how many edits apart they are, using the given
+/// weights.
+///
+/// The score is calculated as
+///
+/// ```text
+/// (weights.metaphone * m + weights.soundex * s + weights.edit * r) / (weights.metaphone + weights.soundex + weights.edit)
+/// ```
+///
+/// where `m` is 1.0 if [metaphone_matches] and 0.0 otherwise, `s` is 1.0 if [soundex_matches]
+/// and 0.0 otherwise, and `r` is the [similarity_ratio](crate::similarity_ratio). Identical
+/// strings always score 1.0, even if they have no ASCII letters to give them phonetic codes.
+///
+/// ```
+/// use fuzzy_string_distance::{phonetic_similarity_with, PhoneticWeights};
+/// // Only care whether the names sound the same
+/// let weights = PhoneticWeights { metaphone: 1.0, soundex: 0.0, edit: 0.0 };
+/// assert_eq!(1.0, phonetic_similarity_with("Philip", "Filip", weights));
+/// assert_eq!(0.0, phonetic_similarity_with("Philip", "Phoebe", weights));
+/// ```
+///
+/// # Panics
+///
+/// If any weight is negative, or all of the weights are 0.0.
+///
+/// Note, the edit distance part of the score compares strings on a unicode scalar value basis,
+/// as per [str::chars]. While this comparison is less likely to cut a 'character' in two than
+/// a byte by byte basis, it still does not compare grapheme clusters.
+pub fn phonetic_similarity_with(source: &str, target: &str, weights: PhoneticWeights) -> f64 {
+    let PhoneticWeights {
+        metaphone,
+        soundex,
+        edit,
+    } = weights;
+    assert!(
+        metaphone >= 0.0 && soundex >= 0.0 && edit >= 0.0,
+        "weights must not be negative"
+    );
+    let total = metaphone + soundex + edit;
+    assert!(total > 0.0, "weights must not all be 0");
+    if source == target {
+        return 1.0;
+    }
+    let indicator = |matches: bool| if matches { 1.0 } else { 0.0 };
+    let score = metaphone * indicator(metaphone_matches(source, target))
+        + soundex * indicator(soundex_matches(source, target))
+        + edit * crate::similarity_ratio(source, target);
+    score / total
+}
+
+/// Returns true if the uppercase ASCII letter is a vowel.
+fn is_vowel(letter: char) -> bool {
+    matches!(letter, 'A' | 'E' | 'I' | 'O' | 'U')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_codes() {
+        let cases = [
+            ("Robert", "R163"),
+            ("Rupert", "R163"),
+            ("Rubin", "R150"),
+            ("Ashcraft", "A261"),
+            ("Ashcroft", "A261"),
+            ("Tymczak", "T522"),
+            ("Pfister", "P236"),
+            ("Honeyman", "H555"),
+            ("Lee", "L000"),
+        ];
+        for (name, code) in cases {
+            assert_eq!(soundex(name), code, "{name}");
+        }
+    }
+
+    #[test]
+    fn ignores_case_and_other_characters() {
+        assert_eq!(soundex("o'brien"), soundex("OBrien"));
+        assert_eq!(soundex("Müller"), "M460");
+        assert_eq!(soundex(""), "");
+        assert_eq!(soundex("123"), "");
+    }
+
+    #[test]
+    fn matches() {
+        assert!(soundex_matches("Robert", "Rupert"));
+        assert!(!soundex_matches("Robert", "Rubin"));
+        assert!(!soundex_matches("", ""));
+    }
+
+    #[test]
+    fn metaphone_codes() {
+        let cases = [
+            ("Philip", "FLP"),
+            ("Knight", "NT"),
+            ("Wright", "RT"),
+            ("Thumb", "0M"),
+            ("Thompson", "TMSN"),
+            ("Thomas", "TMS"),
+            ("Simpson", "SMSN"),
+            ("Judge", "JJ"),
+            ("Xavier", "SFR"),
+            ("Whistle", "WSTL"),
+            ("Science", "SNS"),
+            ("Scene", "SN"),
+            ("Discipline", "TSPLN"),
+            ("Nation", "NXN"),
+            ("Aeon", "EN"),
+            ("Accept", "AKSPT"),
+        ];
+        for (word, code) in cases {
+            assert_eq!(metaphone(word), code, "{word}");
+        }
+    }
+
+    #[test]
+    fn metaphone_silent_letters() {
+        assert_eq!(metaphone("sign"), metaphone("sine"));
+        assert_eq!(metaphone("signed"), "SNT");
+        assert_eq!(metaphone("back"), "BK");
+        assert_eq!(metaphone("watch"), "WX");
+        assert_eq!(metaphone(""), "");
+    }
+
+    #[test]
+    fn metaphone_matching() {
+        assert!(metaphone_matches("Stephen", "Steven"));
+        assert!(metaphone_matches("Philip", "Filip"));
+        assert!(!metaphone_matches("Stephen", "Simon"));
+        assert!(!metaphone_matches("", ""));
+    }
+
+    #[test]
+    fn phonetic_similarity_formula() {
+        // Same metaphone and soundex codes, and 2 edits apart out of 7 characters
+        let result = phonetic_similarity("Stephen", "Steven");
+        assert!((result - (0.3 + 0.2 + 0.5 * (1.0 - 2.0 / 7.0))).abs() < 1e-12);
+        // Only the same soundex code, and 2 edits apart out of 6 characters
+        let result = phonetic_similarity("Robert", "Rupert");
+        assert!((result - (0.2 + 0.5 * (1.0 - 2.0 / 6.0))).abs() < 1e-12);
+        let result = phonetic_similarity("", "");
+        assert_eq!(result, 1.0);
+        let result = phonetic_similarity("abc", "xyz");
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn phonetic_similarity_weights() {
+        let weights = PhoneticWeights {
+            metaphone: 0.0,
+            soundex: 0.0,
+            edit: 2.0,
+        };
+        let result = phonetic_similarity_with("Stephen", "Steven", weights);
+        assert_eq!(result, crate::similarity_ratio("Stephen", "Steven"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_weights() {
+        let weights = PhoneticWeights {
+            metaphone: 0.0,
+            soundex: 0.0,
+            edit: 0.0,
+        };
+        phonetic_similarity_with("a", "b", weights);
+    }
+}
@@ -0,0 +1,311 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The non ASCII punctuation characters removed by [levenshtein_distance_ignore_punctuation],
+/// which are the typographic quotes, dashes and marks that word processors and phone keyboards
+/// often substitute for their ASCII equivalents.
+const TYPOGRAPHIC_PUNCTUATION: [char; 11] = [
+    '\u{2018}', // ‘ left single quotation mark
+    '\u{2019}', // ’ right single quotation mark
+    '\u{201C}', // “ left double quotation mark
+    '\u{201D}', // ” right double quotation mark
+    '\u{2013}', // – en dash
+    '\u{2014}', // — em dash
+    '\u{2026}', // … horizontal ellipsis
+    '\u{AB}',   // « left pointing double angle quotation mark
+    '\u{BB}',   // » right pointing double angle quotation mark
+    '\u{A1}',   // ¡ inverted exclamation mark
+    '\u{BF}',   // ¿ inverted question mark
+];
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string to the
+/// target string after removing all punctuation from both of them.
+///
+/// This is useful for search boxes, where users often leave out apostrophes and commas, or type
+/// an ASCII apostrophe where the text has a typographic one.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_ignore_punctuation;
+/// assert_eq!(0, levenshtein_distance_ignore_punctuation("don't", "dont"));
+/// assert_eq!(0, levenshtein_distance_ignore_punctuation("dont", "don’t"));
+/// assert_eq!(0, levenshtein_distance_ignore_punctuation("Hello, world!", "Hello world"));
+/// ```
+///
+/// The characters removed are the ASCII punctuation characters, as per
+/// [char::is_ascii_punctuation], which are
+///
+/// ```text
+/// ! " # $ % & ' ( ) * + , - . / : ; < = > ? @ [ \ ] ^ _ ` { | } ~
+/// ```
+///
+/// and the typographic quotes, dashes and marks ‘ ’ “ ” – — … « » ¡ ¿ which often stand in for
+/// them. Any other characters, including whitespace and other Unicode punctuation, are compared
+/// as normal. Removing punctuation can join words together, so "co-op" and "coop" are 0 edits
+/// apart.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_ignore_punctuation(source: &str, target: &str) -> usize {
+    let strip = |string: &str| {
+        string
+            .chars()
+            .filter(|&character| !is_punctuation(character))
+            .collect::<Vec<char>>()
+    };
+    crate::levenshtein_distance_slice(&strip(source), &strip(target))
+}
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string to the
+/// target string after removing every character in `ignore` from both of them.
+///
+/// This is useful for comparing identifiers, which are often the same words joined by different
+/// separators.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_ignoring;
+/// let separators = ['_', '-'];
+/// assert_eq!(0, levenshtein_distance_ignoring("foo_bar", "foo-bar", &separators));
+/// assert_eq!(0, levenshtein_distance_ignoring("foo_bar", "foobar", &separators));
+/// assert_eq!(1, levenshtein_distance_ignoring("foo_bar", "foo.bar", &separators));
+/// ```
+///
+/// Ignoring a character is not the same as making substitutions between the ignored characters
+/// free, as with [levenshtein_distance_with](crate::levenshtein_distance_with). That would make
+/// "foo_bar" and "foo-bar" 0 edits apart, but "foo_bar" and "foobar" would still be 1 edit apart
+/// as the underscore has to be deleted. Removing the characters makes inserting or deleting
+/// them free too, wherever they are in either string.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_ignoring(source: &str, target: &str, ignore: &[char]) -> usize {
+    let strip = |string: &str| {
+        string
+            .chars()
+            .filter(|character| !ignore.contains(character))
+            .collect::<Vec<char>>()
+    };
+    crate::levenshtein_distance_slice(&strip(source), &strip(target))
+}
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string to the
+/// target string after collapsing the whitespace in both of them.
+///
+/// Text that has been copied and pasted often has inconsistent spacing, such as two spaces
+/// after a full stop, tabs instead of spaces, or a trailing newline, which would otherwise
+/// count as edits.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_collapse_whitespace;
+/// assert_eq!(0, levenshtein_distance_collapse_whitespace("hello   world", "hello world"));
+/// assert_eq!(0, levenshtein_distance_collapse_whitespace(" hello\tworld\n", "hello world"));
+/// assert_eq!(1, levenshtein_distance_collapse_whitespace("hello   world", "helloworld"));
+/// ```
+///
+/// Both strings are normalized by removing all whitespace from the start and end, then
+/// replacing every remaining run of one or more whitespace characters with a single space
+/// (U+0020). Whitespace is any character with the Unicode `White_Space` property, as per
+/// [char::is_whitespace], which includes tabs, newlines and non-breaking spaces. Whitespace
+/// between words is never removed entirely, so "hello world" and "helloworld" are still 1 edit
+/// apart.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_collapse_whitespace(source: &str, target: &str) -> usize {
+    crate::levenshtein_distance_slice(&collapse_whitespace(source), &collapse_whitespace(target))
+}
+
+/// Trims whitespace from both ends of the string and replaces each run of whitespace within it
+/// with a single space.
+fn collapse_whitespace(string: &str) -> Vec<char> {
+    let mut collapsed = Vec::with_capacity(string.len());
+    for word in string.split_whitespace() {
+        if !collapsed.is_empty() {
+            collapsed.push(' ');
+        }
+        collapsed.extend(word.chars());
+    }
+    collapsed
+}
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string to the
+/// target string after squeezing every run of the same character in both of them down to a
+/// single character.
+///
+/// This lets elongated words such as "soooo" or "yesss", which are common in social media text,
+/// match their usual spelling.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_squeeze_repeats;
+/// assert_eq!(0, levenshtein_distance_squeeze_repeats("soooo good", "so good"));
+/// assert_eq!(0, levenshtein_distance_squeeze_repeats("yesss!!!", "yes!"));
+/// ```
+///
+/// This changes what counts as the same string, not just how far apart strings are. Words which
+/// are only spelled differently by a doubled letter can't be told apart, so "book" and "bok",
+/// or "letter" and "leter", are 0 edits apart. Runs are only squeezed when the characters are
+/// exactly equal, so "Aa" is left as it is.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_squeeze_repeats(source: &str, target: &str) -> usize {
+    let squeeze = |string: &str| {
+        let mut squeezed = string.chars().collect::<Vec<char>>();
+        squeezed.dedup();
+        squeezed
+    };
+    crate::levenshtein_distance_slice(&squeeze(source), &squeeze(target))
+}
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string to the
+/// target string after applying the same normalization to both of them.
+///
+/// This is the general form of functions such as
+/// [levenshtein_distance_collapse_whitespace], where the normalization can be any function from
+/// a string to a new string, so you can combine several normalizations or write your own.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_preprocessed;
+/// // Ignore case and collapse whitespace together
+/// let normalize = |string: &str| {
+///     string.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase()
+/// };
+/// assert_eq!(0, levenshtein_distance_preprocessed("Hello   World", "hello world", normalize));
+/// // Ignore everything except digits
+/// let digits = |string: &str| string.chars().filter(char::is_ascii_digit).collect();
+/// assert_eq!(0, levenshtein_distance_preprocessed("+44 (0)20 7946", "44020-7946", digits));
+/// ```
+///
+/// The normalization is called exactly twice, once with the whole source and once with the
+/// whole target, rather than once per character, so it can look at the context around each
+/// character and can be as expensive as it needs to be.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_preprocessed<F>(source: &str, target: &str, normalize: F) -> usize
+where
+    F: Fn(&str) -> String,
+{
+    crate::levenshtein_distance(&normalize(source), &normalize(target))
+}
+
+/// Returns true for the characters removed by [levenshtein_distance_ignore_punctuation].
+fn is_punctuation(character: char) -> bool {
+    character.is_ascii_punctuation() || TYPOGRAPHIC_PUNCTUATION.contains(&character)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apostrophes() {
+        let result = levenshtein_distance_ignore_punctuation("don't", "dont");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_ignore_punctuation("don’t", "don't");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_ignore_punctuation("won't", "dont");
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn only_punctuation_removed() {
+        let result = levenshtein_distance_ignore_punctuation("Hello, world!", "Hello world");
+        assert_eq!(result, 0);
+        // Whitespace is still compared
+        let result = levenshtein_distance_ignore_punctuation("Hello, world!", "Helloworld");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_ignore_punctuation("?!...", "");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn collapsing_whitespace() {
+        let result = levenshtein_distance_collapse_whitespace("hello  world", "hello world");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_collapse_whitespace("hello   world", "hello world");
+        assert_eq!(result, 0);
+        let result =
+            levenshtein_distance_collapse_whitespace("\n hello \u{a0}\t world \r\n", "hello world");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_collapse_whitespace("   ", "");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn whitespace_between_words_kept() {
+        let result = levenshtein_distance_collapse_whitespace("hello   world", "helloworld");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_collapse_whitespace("a  b  c", "a b c d");
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn squeezing_repeats() {
+        let result = levenshtein_distance_squeeze_repeats("soooo", "so");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_squeeze_repeats("sooo goooood", "so god");
+        assert_eq!(result, 0);
+        // Doubled letters can't be told apart from single ones
+        let result = levenshtein_distance_squeeze_repeats("book", "bok");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_squeeze_repeats("Sooo", "sO");
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn preprocessed() {
+        let uppercase = |string: &str| string.to_uppercase();
+        let result = levenshtein_distance_preprocessed("rust", "RUST", uppercase);
+        assert_eq!(result, 0);
+        // ß uppercases to SS, so the normalization can change the length
+        let result = levenshtein_distance_preprocessed("straße", "STRASSE", uppercase);
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_preprocessed("rust", "rusty", uppercase);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn preprocessed_calls() {
+        let calls = core::cell::Cell::new(0);
+        let normalize = |string: &str| {
+            calls.set(calls.get() + 1);
+            String::from(string)
+        };
+        let result = levenshtein_distance_preprocessed("kitten", "sitting", normalize);
+        assert_eq!(result, 3);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn ignoring_characters() {
+        let separators = ['_', '-'];
+        for (source, target) in [
+            ("foo_bar", "foo-bar"),
+            ("foo_bar", "foobar"),
+            ("foo-bar", "foobar"),
+            ("_foo__bar-", "foo-bar"),
+        ] {
+            assert_eq!(
+                levenshtein_distance_ignoring(source, target, &separators),
+                0
+            );
+        }
+        let result = levenshtein_distance_ignoring("foo_bar", "foo bar", &separators);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn ignoring_nothing() {
+        let result = levenshtein_distance_ignoring("foo_bar", "foo-bar", &[]);
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_ignoring("kitten", "sitting", &[]);
+        assert_eq!(result, 3);
+        let result = levenshtein_distance_ignoring("ørn", "orn", &['ø']);
+        assert_eq!(result, 1);
+    }
+}
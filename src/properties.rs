@@ -0,0 +1,85 @@
+// Property tests checking the invariants every distance should satisfy against randomly
+// generated strings, and that the optimised implementations agree with levenshtein_distance.
+
+use proptest::prelude::*;
+
+use crate::*;
+
+/// Strings over a small alphabet, so random pairs share plenty of characters and have
+/// interesting distances, including a couple of multi byte characters.
+fn string() -> impl Strategy<Value = String> {
+    "[abcé日]{0,16}"
+}
+
+proptest! {
+    #[test]
+    fn identity(a in string()) {
+        prop_assert_eq!(levenshtein_distance(&a, &a), 0);
+        prop_assert_eq!(damerau_levenshtein_distance(&a, &a), 0);
+        prop_assert_eq!(local_levenshtein_distance(&a, &a), 0);
+    }
+
+    #[test]
+    fn symmetry(a in string(), b in string()) {
+        prop_assert_eq!(levenshtein_distance(&a, &b), levenshtein_distance(&b, &a));
+        prop_assert_eq!(
+            damerau_levenshtein_distance(&a, &b),
+            damerau_levenshtein_distance(&b, &a),
+        );
+        prop_assert_eq!(
+            damerau_levenshtein_distance_unrestricted(&a, &b),
+            damerau_levenshtein_distance_unrestricted(&b, &a),
+        );
+    }
+
+    #[test]
+    fn triangle_inequality(a in string(), b in string(), c in string()) {
+        prop_assert!(
+            levenshtein_distance(&a, &c) <= levenshtein_distance(&a, &b) + levenshtein_distance(&b, &c)
+        );
+        prop_assert!(
+            damerau_levenshtein_distance_unrestricted(&a, &c)
+                <= damerau_levenshtein_distance_unrestricted(&a, &b)
+                    + damerau_levenshtein_distance_unrestricted(&b, &c)
+        );
+    }
+
+    #[test]
+    fn bounded_by_lengths(a in string(), b in string()) {
+        let a_chars = a.chars().count();
+        let b_chars = b.chars().count();
+        let distance = levenshtein_distance(&a, &b);
+        prop_assert!(distance <= a_chars.max(b_chars));
+        prop_assert!(distance >= a_chars.abs_diff(b_chars));
+        prop_assert_eq!(distance_bounds(&a, &b).0, min_possible_distance(&a, &b));
+        let (lower, upper) = distance_bounds(&a, &b);
+        prop_assert!(lower <= distance && distance <= upper);
+        prop_assert!(local_levenshtein_distance(&a, &b) <= distance);
+    }
+
+    #[test]
+    fn optimised_implementations_agree(a in string(), b in string(), k in 0..20_usize) {
+        let distance = levenshtein_distance(&a, &b);
+        prop_assert_eq!(levenshtein_distance_myers(&a, &b), distance);
+        prop_assert_eq!(Levenshtein::new().distance(&a, &b), distance);
+        let source = a.chars().collect::<Vec<char>>();
+        let target = b.chars().collect::<Vec<char>>();
+        prop_assert_eq!(levenshtein_distance_slice(&source, &target), distance);
+        prop_assert_eq!(levenshtein_distance_iter(a.chars(), b.chars()), distance);
+        prop_assert_eq!(
+            levenshtein_distance_weighted(&a, &b, Costs::default()),
+            distance,
+        );
+        let within = if distance <= k { Some(distance) } else { None };
+        prop_assert_eq!(levenshtein_distance_within(&a, &b, k), within);
+        prop_assert_eq!(levenshtein_distance_banded(&a, &b, k), within);
+    }
+
+    #[test]
+    fn ascii_implementations_agree(a in "[abc]{0,40}", b in "[abc]{0,40}") {
+        let distance = levenshtein_distance(&a, &b);
+        prop_assert_eq!(levenshtein_distance_ascii(a.as_bytes(), b.as_bytes()), distance);
+        prop_assert_eq!(levenshtein_distance_bytes(a.as_bytes(), b.as_bytes()), distance);
+        prop_assert_eq!(usize::from(levenshtein_distance_u8(&a, &b)), distance);
+    }
+}
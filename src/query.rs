@@ -0,0 +1,83 @@
+use alloc::vec::Vec;
+
+/// A source string prepared for comparing against many target strings.
+///
+/// Each call to [levenshtein_distance](crate::levenshtein_distance) has to decode both of its
+/// inputs into characters. When comparing one fixed query against a stream of targets, a
+/// `Query` decodes the query once up front, so each comparison only has to decode the target.
+///
+/// ```
+/// use fuzzy_string_distance::Query;
+/// let query = Query::new("rust");
+/// let words = ["rusty", "crust", "trust", "dust", "ferris"];
+/// let close = words
+///     .into_iter()
+///     .filter(|word| query.distance(word) <= 1)
+///     .collect::<Vec<&str>>();
+/// assert_eq!(vec!["rusty", "crust", "trust", "dust"], close);
+/// ```
+///
+/// Unlike [Levenshtein](crate::Levenshtein), a `Query` doesn't keep buffers between calls, so
+/// it can be shared between threads and only needs `&self` to compare.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Query {
+    source: Vec<char>,
+}
+
+impl Query {
+    /// Creates a new Query from the source string, decoding its characters once.
+    pub fn new(source: &str) -> Self {
+        Query {
+            source: source.chars().collect(),
+        }
+    }
+
+    /// Returns the number of characters in the source string.
+    pub fn len(&self) -> usize {
+        self.source.len()
+    }
+
+    /// Returns true if the source string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.source.is_empty()
+    }
+
+    /// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string
+    /// this Query was created from to the target string.
+    pub fn distance(&self, target: &str) -> usize {
+        // The target has at most as many characters as bytes, so this only allocates once
+        let mut target_chars = Vec::with_capacity(target.len());
+        target_chars.extend(target.chars());
+        crate::levenshtein_distance_slice(&self.source, &target_chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein_distance;
+
+    #[test]
+    fn same_as_levenshtein_distance() {
+        let query = Query::new("kitten");
+        assert_eq!(query.len(), 6);
+        for target in ["sitting", "", "kitten", "mitten", "El delfín español"] {
+            assert_eq!(
+                query.distance(target),
+                levenshtein_distance("kitten", target)
+            );
+        }
+    }
+
+    #[test]
+    fn empty_query() {
+        let query = Query::new("");
+        assert!(query.is_empty());
+        assert_eq!(query, Query::default());
+        assert_eq!(query.distance("rust"), 4);
+    }
+}
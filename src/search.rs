@@ -0,0 +1,351 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+
+use crate::Levenshtein;
+
+/// Returns the candidate with the smallest [levenshtein_distance](crate::levenshtein_distance)
+/// to the query, along with that distance, or `None` if there are no candidates.
+///
+/// If several candidates are equally close to the query, the first of them is returned.
+///
+/// ```
+/// use fuzzy_string_distance::best_match;
+/// let commands = ["install", "uninstall", "update", "list"];
+/// assert_eq!(Some(("install", 1)), best_match("instal", commands));
+/// assert_eq!(None, best_match("instal", []));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn best_match<'a>(
+    query: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<(&'a str, usize)> {
+    let mut levenshtein = Levenshtein::new();
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, levenshtein.distance(query, candidate)))
+        // min_by_key returns the first of any equal minimums
+        .min_by_key(|&(_, distance)| distance)
+}
+
+/// Returns the index of the candidate with the smallest
+/// [levenshtein_distance](crate::levenshtein_distance) to the query, along with that distance,
+/// or `None` if there are no candidates.
+///
+/// This is the same as [best_match], but returns where the closest candidate is in the slice
+/// instead of the candidate itself, for looking up data stored alongside it. If several
+/// candidates are equally close to the query, the one with the lowest index is returned.
+///
+/// ```
+/// use fuzzy_string_distance::best_match_index;
+/// let commands = ["install", "uninstall", "update", "list"];
+/// let descriptions = ["Installs a package", "Removes a package", "Updates", "Lists"];
+/// let (index, distance) = best_match_index("instal", &commands).unwrap();
+/// assert_eq!((0, 1), (index, distance));
+/// assert_eq!("Installs a package", descriptions[index]);
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn best_match_index(query: &str, candidates: &[&str]) -> Option<(usize, usize)> {
+    let mut levenshtein = Levenshtein::new();
+    candidates
+        .iter()
+        .map(|candidate| levenshtein.distance(query, candidate))
+        .enumerate()
+        // min_by_key returns the first of any equal minimums
+        .min_by_key(|&(_, distance)| distance)
+}
+
+/// Returns up to `k` of the candidates with the smallest
+/// [levenshtein_distance](crate::levenshtein_distance) to the query, along with their distances,
+/// sorted by ascending distance.
+///
+/// Candidates that are equally close to the query are kept in the order they were given, so if
+/// there are more than `k` candidates tied for the last place, the earliest of them are
+/// returned. Only `k` candidates are kept at any one time, so this takes memory proportional to
+/// `k` rather than to the number of candidates.
+///
+/// ```
+/// use fuzzy_string_distance::k_nearest;
+/// let commands = ["install", "uninstall", "update", "list", "instance"];
+/// assert_eq!(
+///     vec![("install", 1), ("uninstall", 3)],
+///     k_nearest("instal", commands, 2),
+/// );
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn k_nearest<'a>(
+    query: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+    k: usize,
+) -> Vec<(&'a str, usize)> {
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut levenshtein = Levenshtein::new();
+    // A max heap of the closest candidates so far, ordered by distance and then by position,
+    // so the top of the heap is always the furthest candidate we've kept and the latest of any
+    // candidates tied for that distance
+    let mut nearest = BinaryHeap::with_capacity(k + 1);
+    for (position, candidate) in candidates.into_iter().enumerate() {
+        let distance = levenshtein.distance(query, candidate);
+        if nearest.len() < k {
+            nearest.push((distance, position, candidate));
+        } else if let Some(&(furthest, _, _)) = nearest.peek() {
+            // Any later candidate with the same distance as the furthest we've kept comes after
+            // it in the input, so we only need to replace the furthest with strictly closer ones
+            if distance < furthest {
+                nearest.pop();
+                nearest.push((distance, position, candidate));
+            }
+        }
+    }
+    nearest
+        .into_sorted_vec()
+        .into_iter()
+        .map(|(distance, _, candidate)| (candidate, distance))
+        .collect()
+}
+
+/// Returns the valid option closest to the input, if it is within `max_distance` of it, for
+/// suggesting a correction when a user mistypes something such as a subcommand.
+///
+/// This is the same as taking the [best_match] and then checking its distance, so the closest
+/// option by [levenshtein_distance](crate::levenshtein_distance) is suggested, and if several
+/// options are equally close the first of them is suggested. If no option is within
+/// `max_distance` there is nothing close enough to be a plausible correction, so `None` is
+/// returned.
+///
+/// ```
+/// use fuzzy_string_distance::suggest;
+/// let commands = ["install", "uninstall", "update", "list"];
+/// let input = "instll";
+/// assert_eq!(Some("install"), suggest(input, &commands, 2));
+/// let suggestion = suggest(input, &commands, 2).unwrap();
+/// assert_eq!(
+///     "error: unknown command `instll`; did you mean `install`?",
+///     format!("error: unknown command `{input}`; did you mean `{suggestion}`?"),
+/// );
+/// assert_eq!(None, suggest("remove", &commands, 2));
+/// ```
+///
+/// A `max_distance` of around a third of the input's length catches most typos without
+/// suggesting unrelated options for short inputs.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn suggest<'a>(input: &str, valid: &[&'a str], max_distance: usize) -> Option<&'a str> {
+    best_match(input, valid.iter().copied())
+        .filter(|&(_, distance)| distance <= max_distance)
+        .map(|(option, _)| option)
+}
+
+/// Returns up to `limit` of the valid options closest to the input which are within
+/// `max_distance` of it, sorted by ascending distance, for suggesting several corrections when a
+/// user mistypes something and more than one is plausible.
+///
+/// This is like [suggest], but for when there can be more than one suggestion. Options that are
+/// equally close to the input are kept in the order they were given, so if there are more than
+/// `limit` options within `max_distance`, the closest and then earliest of them are returned.
+///
+/// ```
+/// use fuzzy_string_distance::suggestions;
+/// let commands = ["install", "uninstall", "update", "list"];
+/// let found = suggestions("unstall", &commands, 2, 3);
+/// assert_eq!(vec!["install", "uninstall"], found);
+/// assert_eq!(
+///     "did you mean one of: install, uninstall?",
+///     format!("did you mean one of: {}?", found.join(", ")),
+/// );
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn suggestions<'a>(
+    input: &str,
+    valid: &[&'a str],
+    max_distance: usize,
+    limit: usize,
+) -> Vec<&'a str> {
+    k_nearest(input, valid.iter().copied(), limit)
+        .into_iter()
+        // The nearest options are sorted by distance, so once one is too far so are the rest
+        .take_while(|&(_, distance)| distance <= max_distance)
+        .map(|(option, _)| option)
+        .collect()
+}
+
+/// Sorts the items in place by ascending [levenshtein_distance](crate::levenshtein_distance) to
+/// the query.
+///
+/// Each item's distance to the query is only calculated once, rather than every time two items
+/// are compared. The sort is stable, so items which are equally close to the query keep their
+/// original order.
+///
+/// ```
+/// use fuzzy_string_distance::sort_by_distance;
+/// let mut words = ["trust", "rusty", "rust", "crust", "dusty"];
+/// sort_by_distance(&mut words, "rust");
+/// assert_eq!(["rust", "trust", "rusty", "crust", "dusty"], words);
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn sort_by_distance<S: AsRef<str>>(items: &mut [S], query: &str) {
+    let mut levenshtein = Levenshtein::new();
+    // sort_by_cached_key computes each key once up front and then sorts by the keys
+    items.sort_by_cached_key(|item| levenshtein.distance(query, item.as_ref()));
+}
+
+/// Returns a copy of the items sorted by ascending
+/// [levenshtein_distance](crate::levenshtein_distance) to the query, leaving the original items
+/// unchanged.
+///
+/// This is the same as [sort_by_distance] for when the items can't be reordered in place.
+///
+/// ```
+/// use fuzzy_string_distance::sorted_by_distance;
+/// let words = vec!["trust".to_string(), "rusty".to_string(), "rust".to_string()];
+/// assert_eq!(vec!["rust", "trust", "rusty"], sorted_by_distance(&words, "rust"));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn sorted_by_distance<S: AsRef<str> + Clone>(items: &[S], query: &str) -> Vec<S> {
+    let mut sorted = items.to_vec();
+    sort_by_distance(&mut sorted, query);
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn best() {
+        let candidates = ["kitten", "sitting", "mitten", "fitting"];
+        let result = best_match("sittin", candidates);
+        assert_eq!(result, Some(("sitting", 1)));
+    }
+
+    #[test]
+    fn best_ties() {
+        // kitten and mitten are both 1 edit away, pick the first
+        let candidates = ["sitting", "kitten", "mitten"];
+        let result = best_match("bitten", candidates);
+        assert_eq!(result, Some(("kitten", 1)));
+    }
+
+    #[test]
+    fn best_empty() {
+        let result = best_match("rust", []);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn best_index() {
+        let candidates = ["kitten", "sitting", "mitten", "fitting"];
+        let result = best_match_index("sittin", &candidates);
+        assert_eq!(result, Some((1, 1)));
+        // kitten and mitten are both 1 edit away, pick the lowest index
+        let result = best_match_index("bitten", &candidates);
+        assert_eq!(result, Some((0, 1)));
+        let result = best_match_index("bitten", &[]);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn nearest() {
+        let candidates = ["kitten", "sitting", "mitten", "fitting", "bitten"];
+        let result = k_nearest("sittin", candidates, 3);
+        assert_eq!(result, vec![("sitting", 1), ("kitten", 2), ("mitten", 2)]);
+    }
+
+    #[test]
+    fn nearest_ties() {
+        // Every candidate is 1 edit away, so the first ones given are kept
+        let candidates = ["cat", "bat", "hat", "mat", "rat"];
+        let result = k_nearest("at", candidates, 3);
+        assert_eq!(result, vec![("cat", 1), ("bat", 1), ("hat", 1)]);
+    }
+
+    #[test]
+    fn nearest_fewer_than_k() {
+        let result = k_nearest("rust", ["rusty", "trust"], 5);
+        assert_eq!(result, vec![("rusty", 1), ("trust", 1)]);
+        let result = k_nearest("rust", ["rusty", "trust"], 0);
+        assert_eq!(result, vec![]);
+    }
+
+    #[test]
+    fn suggesting_subcommand() {
+        let commands = ["build", "check", "clean", "doc", "new", "run", "test"];
+        let result = suggest("biuld", &commands, 2);
+        assert_eq!(result, Some("build"));
+        let result = suggest("tset", &commands, 2);
+        assert_eq!(result, Some("test"));
+    }
+
+    #[test]
+    fn nothing_to_suggest() {
+        let commands = ["build", "check", "clean"];
+        let result = suggest("publish", &commands, 2);
+        assert_eq!(result, None);
+        let result = suggest("build", &[], 2);
+        assert_eq!(result, None);
+        // Exactly at the threshold is still suggested
+        let result = suggest("chck", &commands, 1);
+        assert_eq!(result, Some("check"));
+    }
+
+    #[test]
+    fn suggesting_several() {
+        let commands = ["install", "uninstall", "update", "list"];
+        // unstall is 1 edit from install and 2 from uninstall
+        let result = suggestions("unstall", &commands, 2, 5);
+        assert_eq!(result, vec!["install", "uninstall"]);
+        let result = suggestions("unstall", &commands, 2, 1);
+        assert_eq!(result, vec!["install"]);
+        let result = suggestions("upd", &commands, 3, 5);
+        assert_eq!(result, vec!["update"]);
+    }
+
+    #[test]
+    fn suggesting_none() {
+        let commands = ["install", "uninstall", "update", "list"];
+        let result = suggestions("publish", &commands, 2, 5);
+        assert_eq!(result, Vec::<&str>::new());
+        let result = suggestions("unstall", &commands, 2, 0);
+        assert_eq!(result, Vec::<&str>::new());
+    }
+
+    #[test]
+    fn sorting() {
+        let mut items = vec!["fitting", "kitten", "sitting", "mitten"];
+        sort_by_distance(&mut items, "sittin");
+        assert_eq!(items, vec!["sitting", "fitting", "kitten", "mitten"]);
+    }
+
+    #[test]
+    fn sorted_copy() {
+        let items = [
+            "fitting".to_string(),
+            "kitten".to_string(),
+            "sitting".to_string(),
+        ];
+        let result = sorted_by_distance(&items, "sittin");
+        assert_eq!(result, vec!["sitting", "fitting", "kitten"]);
+        assert_eq!(items, ["fitting", "kitten", "sitting"]);
+    }
+}
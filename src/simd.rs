@@ -0,0 +1,127 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Returns the Levenshtein distance between the source and target bytes by computing the
+/// matrix of edit distances one anti-diagonal at a time, or `None` if either input is too long
+/// for the distances to fit in 16 bits.
+///
+/// Every entry on an anti-diagonal, where the row plus the column is the same, only depends on
+/// entries from the previous two anti-diagonals, so unlike a row of the matrix there is no
+/// dependency from one entry to the next. With the target reversed, the bytes compared along an
+/// anti-diagonal are also contiguous in both inputs, so the inner loop is a straight run over
+/// equal length slices which the compiler turns into SIMD instructions, processing many entries
+/// at once in 16 bit lanes.
+pub(crate) fn levenshtein_distance_anti_diagonal(source: &[u8], target: &[u8]) -> Option<usize> {
+    // The distance is never more than the length of the longer input, and we add 1 to
+    // distances before taking minimums, so this keeps every entry from overflowing
+    if source.len() >= usize::from(u16::MAX) || target.len() >= usize::from(u16::MAX) {
+        return None;
+    }
+    let source_bytes = source.len();
+    let target_bytes = target.len();
+    if source.is_empty() {
+        return Some(target_bytes);
+    }
+    if target.is_empty() {
+        return Some(source_bytes);
+    }
+
+    // Walking down an anti-diagonal the row goes up by one while the column goes down by one,
+    // so we read the target backwards. Reversing it up front makes those reads contiguous.
+    let reversed_target = target.iter().rev().copied().collect::<Vec<u8>>();
+
+    // Each anti-diagonal is indexed by row, from 0 to the source length, with only the entries
+    // that are inside the matrix ever read. We need the previous two anti-diagonals as well as
+    // the one we're calculating.
+    let mut before_previous = vec![0_u16; source_bytes + 1];
+    let mut previous = vec![0_u16; source_bytes + 1];
+    let mut current = vec![0_u16; source_bytes + 1];
+
+    for k in 0..=(source_bytes + target_bytes) {
+        // A[0, k] is converting an empty string to a prefix of the target, and A[k, 0] is
+        // converting a prefix of the source to an empty string, both of which take k edits
+        if k <= target_bytes {
+            current[0] = k as u16;
+        }
+        if k <= source_bytes {
+            current[k] = k as u16;
+        }
+        // The rows of the entries on this anti-diagonal that aren't on the first row or column
+        let first = core::cmp::max(1, k.saturating_sub(target_bytes));
+        let last = core::cmp::min(source_bytes, k.saturating_sub(1));
+        if first <= last {
+            let length = last - first + 1;
+            // A[i, j] is on anti-diagonal i + j, so A[i - 1, j] and A[i, j - 1] are on the
+            // previous anti-diagonal in rows i - 1 and i, and A[i - 1, j - 1] is on the one
+            // before that in row i - 1. We compare source[i - 1] against target[j - 1], which
+            // is reversed_target[target length - j], and j is k - i.
+            let above = &previous[first - 1..first - 1 + length];
+            let left = &previous[first..first + length];
+            let diagonal = &before_previous[first - 1..first - 1 + length];
+            let source_slice = &source[first - 1..first - 1 + length];
+            let offset = target_bytes + first - k;
+            let target_slice = &reversed_target[offset..offset + length];
+            let output = &mut current[first..first + length];
+            for x in 0..length {
+                let substitution = diagonal[x] + u16::from(source_slice[x] != target_slice[x]);
+                let deletion_or_insertion = core::cmp::min(above[x], left[x]) + 1;
+                output[x] = core::cmp::min(substitution, deletion_or_insertion);
+            }
+        }
+        // Move along to the next anti-diagonal, reusing the oldest buffer for it
+        core::mem::swap(&mut before_previous, &mut previous);
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    // A[source length, target length] is in the last row of the last anti-diagonal
+    Some(usize::from(previous[source_bytes]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein_distance_slice;
+
+    /// Generates a pseudo random byte string from a small alphabet so that random pairs share
+    /// plenty of bytes.
+    fn random_bytes(state: &mut u32, max_length: u32) -> Vec<u8> {
+        let mut next = || {
+            *state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            *state >> 16
+        };
+        let length = next() % (max_length + 1);
+        (0..length).map(|_| b"abcd"[next() as usize % 4]).collect()
+    }
+
+    #[test]
+    fn short_strings() {
+        let result = levenshtein_distance_anti_diagonal(b"kitten", b"sitting");
+        assert_eq!(result, Some(3));
+        let result = levenshtein_distance_anti_diagonal(b"", b"rust");
+        assert_eq!(result, Some(4));
+        let result = levenshtein_distance_anti_diagonal(b"bug", b"");
+        assert_eq!(result, Some(3));
+    }
+
+    #[test]
+    fn same_as_scalar() {
+        let mut state = 3;
+        for max_length in [8, 40, 300] {
+            for _ in 0..1000 {
+                let source = random_bytes(&mut state, max_length);
+                let target = random_bytes(&mut state, max_length);
+                assert_eq!(
+                    levenshtein_distance_anti_diagonal(&source, &target),
+                    Some(levenshtein_distance_slice(&source, &target)),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn too_long() {
+        let long = vec![b'a'; usize::from(u16::MAX)];
+        assert_eq!(levenshtein_distance_anti_diagonal(&long, b"a"), None);
+        assert_eq!(levenshtein_distance_anti_diagonal(b"a", &long), None);
+    }
+}
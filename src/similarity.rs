@@ -0,0 +1,438 @@
+use crate::{levenshtein_distance, levenshtein_distance_within};
+
+/// Returns a similarity score between 0.0 and 1.0 for the source and target strings, based on
+/// their [levenshtein_distance] relative to the length of the longer string.
+///
+/// This is calculated as `1.0 - distance / max(source length, target length)`, where lengths
+/// are counted in characters. Two identical strings have a similarity of 1.0, including two
+/// empty strings, and two completely unrelated strings have a similarity of 0.0.
+///
+/// Unlike the raw distance, this is comparable across strings of different lengths, as one typo
+/// in a long sentence is much less significant than one typo in a short word.
+///
+/// ```
+/// use fuzzy_string_distance::similarity_ratio;
+/// assert_eq!(0.8, similarity_ratio("rust", "rusty"));
+/// assert_eq!(1.0, similarity_ratio("rust", "rust"));
+/// assert_eq!(0.0, similarity_ratio("abc", "xyz"));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn similarity_ratio(source: &str, target: &str) -> f64 {
+    let longest = core::cmp::max(source.chars().count(), target.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    let distance = levenshtein_distance(source, target);
+    ratio(distance, longest)
+}
+
+/// Returns a similarity percentage between 0 and 100 for the source and target strings, which
+/// is the [similarity_ratio] multiplied by 100 and rounded to the nearest integer.
+///
+/// Values exactly halfway between two integers are rounded up, so a ratio of 0.125 gives 13.
+///
+/// ```
+/// use fuzzy_string_distance::similarity_percent;
+/// assert_eq!(80, similarity_percent("rust", "rusty"));
+/// assert_eq!(100, similarity_percent("rust", "rust"));
+/// assert_eq!(0, similarity_percent("abc", "xyz"));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn similarity_percent(source: &str, target: &str) -> u8 {
+    let percent = similarity_ratio(source, target) * 100.0;
+    // The ratio is never negative, so adding a half and then truncating towards zero with `as`
+    // rounds half up. Casting a float to an integer saturates, but we still need to clamp to
+    // 100 in case of floating point error.
+    core::cmp::min((percent + 0.5) as u8, 100)
+}
+
+/// Returns true if the [levenshtein_distance] from the source string to the target string is no
+/// greater than `max_distance`.
+///
+/// This stops comparing the strings as soon as it is provable that the distance will be greater
+/// than `max_distance`, as per [levenshtein_distance_within], so it is much cheaper than
+/// calculating the full distance when the strings are very different.
+///
+/// ```
+/// use fuzzy_string_distance::is_similar;
+/// assert!(is_similar("rust", "rusty", 1));
+/// assert!(!is_similar("rust", "typography", 2));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn is_similar(source: &str, target: &str, max_distance: usize) -> bool {
+    levenshtein_distance_within(source, target, max_distance).is_some()
+}
+
+/// Returns true if the [similarity_ratio] of the source and target strings is at least
+/// `min_ratio`.
+///
+/// As with [is_similar], this stops comparing the strings as soon as it is provable that the
+/// ratio will be less than `min_ratio`. Use [similarity_at_least] to get the ratio as well.
+///
+/// ```
+/// use fuzzy_string_distance::is_similar_ratio;
+/// assert!(is_similar_ratio("rust", "rusty", 0.8));
+/// assert!(!is_similar_ratio("rust", "rusty", 0.9));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn is_similar_ratio(source: &str, target: &str, min_ratio: f64) -> bool {
+    similarity_at_least(source, target, min_ratio).is_some()
+}
+
+/// Returns the [similarity_ratio] of the source and target strings if it is at least
+/// `min_ratio`, or `None` if it is less than `min_ratio`.
+///
+/// The ratio threshold is converted into a maximum number of edits, and the strings are
+/// compared with [levenshtein_distance_within], which stops as soon as it is provable that the
+/// distance is over that maximum. This means obviously dissimilar strings are rejected without
+/// working out exactly how dissimilar they are, while the exact ratio is still returned for the
+/// strings that meet the bar.
+///
+/// ```
+/// use fuzzy_string_distance::similarity_at_least;
+/// assert_eq!(Some(0.8), similarity_at_least("rust", "rusty", 0.8));
+/// assert_eq!(None, similarity_at_least("rust", "rusty", 0.9));
+/// assert_eq!(None, similarity_at_least("rust", "typography", 0.5));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn similarity_at_least(source: &str, target: &str, min_ratio: f64) -> Option<f64> {
+    let longest = core::cmp::max(source.chars().count(), target.chars().count());
+    if longest == 0 {
+        return (1.0 >= min_ratio).then_some(1.0);
+    }
+    // The ratio is at least `min_ratio` when the distance is at most
+    // `(1.0 - min_ratio) * longest`, but floating point error could put that bound just below
+    // a whole number, so we allow one more edit than the bound and then check the ratio exactly
+    // the same way as similarity_ratio does. Casting to an integer saturates, so very small or
+    // negative ratios just never stop early.
+    let max_distance = (((1.0 - min_ratio) * longest as f64) as usize).saturating_add(1);
+    let distance = levenshtein_distance_within(source, target, max_distance)?;
+    let ratio = ratio(distance, longest);
+    (ratio >= min_ratio).then_some(ratio)
+}
+
+/// How much each metric contributes to the score of [combined_similarity].
+///
+/// The weights don't need to add up to 1.0, as the score is divided by their total. The default
+/// weights are 0.5 for the [similarity_ratio], and 0.25 each for the
+/// [jaro_winkler_similarity](crate::jaro_winkler_similarity) and the bigram
+/// [ngram_jaccard_similarity](crate::ngram_jaccard_similarity).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimilarityWeights {
+    /// The weight given to the [similarity_ratio], based on the Levenshtein distance
+    pub levenshtein: f64,
+    /// The weight given to the [jaro_winkler_similarity](crate::jaro_winkler_similarity)
+    pub jaro_winkler: f64,
+    /// The weight given to the [ngram_jaccard_similarity](crate::ngram_jaccard_similarity) of
+    /// the bigrams of the strings
+    pub ngram: f64,
+}
+
+impl Default for SimilarityWeights {
+    fn default() -> Self {
+        SimilarityWeights {
+            levenshtein: 0.5,
+            jaro_winkler: 0.25,
+            ngram: 0.25,
+        }
+    }
+}
+
+/// Returns a similarity score between 0.0 and 1.0 for the source and target strings, which is a
+/// weighted average of several different similarity metrics.
+///
+/// Each metric picks up on different kinds of similarity, so blending them can rank candidates
+/// better than any one of them alone. The score is calculated as
+///
+/// ```text
+/// (weights.levenshtein * l + weights.jaro_winkler * j + weights.ngram * n) / (weights.levenshtein + weights.jaro_winkler + weights.ngram)
+/// ```
+///
+/// where each metric is already a score between 0.0 and 1.0 where 1.0 means the strings are
+/// identical:
+/// - `l` is the [similarity_ratio], 1.0 minus the Levenshtein distance divided by the length of
+///   the longer string, which measures how many edits apart the strings are.
+/// - `j` is the [jaro_winkler_similarity](crate::jaro_winkler_similarity), which measures how
+///   many characters the strings have in common near the same positions, with a bonus for a
+///   common prefix.
+/// - `n` is the [ngram_jaccard_similarity](crate::ngram_jaccard_similarity) with n = 2, the
+///   number of bigrams in both strings divided by the number of bigrams in either, which
+///   measures how much of the local structure of the strings is shared regardless of where.
+///
+/// ```
+/// use fuzzy_string_distance::{combined_similarity, SimilarityWeights};
+/// let score = combined_similarity("night", "nacht", SimilarityWeights::default());
+/// assert!(score > 0.0 && score < 1.0);
+/// let weights = SimilarityWeights { levenshtein: 1.0, jaro_winkler: 0.0, ngram: 0.0 };
+/// assert_eq!(0.6, combined_similarity("night", "nacht", weights));
+/// assert_eq!(1.0, combined_similarity("night", "night", SimilarityWeights::default()));
+/// ```
+///
+/// # Panics
+///
+/// If any weight is negative, or all of the weights are 0.0.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn combined_similarity(source: &str, target: &str, weights: SimilarityWeights) -> f64 {
+    let SimilarityWeights {
+        levenshtein,
+        jaro_winkler,
+        ngram,
+    } = weights;
+    assert!(
+        levenshtein >= 0.0 && jaro_winkler >= 0.0 && ngram >= 0.0,
+        "weights must not be negative"
+    );
+    let total = levenshtein + jaro_winkler + ngram;
+    assert!(total > 0.0, "weights must not all be 0");
+    let score = levenshtein * similarity_ratio(source, target)
+        + jaro_winkler * crate::jaro_winkler_similarity(source, target)
+        + ngram * crate::ngram_jaccard_similarity(source, target, 2);
+    score / total
+}
+
+/// Returns a similarity score between 0.0 and 1.0 for the source and target strings, which is
+/// the [similarity_ratio] plus a bonus for the length of the prefix they have in common.
+///
+/// This is calculated as `similarity_ratio + prefix_weight * prefix / max(source length, target
+/// length)`, where `prefix` is the number of characters at the start of both strings that are
+/// the same, and all lengths are counted in characters. The bonus can push the score above 1.0,
+/// so it is clamped to 1.0. Two empty strings have a similarity of 1.0.
+///
+/// This ranks strings which start the same way above strings with the same number of edits
+/// elsewhere, which suits autocomplete where the user types the start of what they want. It is
+/// simpler to reason about than
+/// [jaro_winkler_similarity](crate::jaro_winkler_similarity), as the bonus is proportional to
+/// the whole common prefix rather than capped at a few characters.
+///
+/// ```
+/// use fuzzy_string_distance::{prefix_similarity, similarity_ratio};
+/// // Both are 1 edit from the query, but only one starts the same way
+/// assert_eq!(0.75, similarity_ratio("card", "care"));
+/// assert_eq!(0.75, similarity_ratio("card", "ward"));
+/// assert_eq!(0.75 + 0.25 * 3.0 / 4.0, prefix_similarity("card", "care", 0.25));
+/// assert_eq!(0.75, prefix_similarity("card", "ward", 0.25));
+/// // 0.8 + 1.0 * 4.0 / 5.0 is clamped to 1.0
+/// assert_eq!(1.0, prefix_similarity("rust", "rusty", 1.0));
+/// ```
+///
+/// # Panics
+///
+/// If `prefix_weight` is negative.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn prefix_similarity(source: &str, target: &str, prefix_weight: f64) -> f64 {
+    assert!(prefix_weight >= 0.0, "prefix weight must not be negative");
+    let longest = core::cmp::max(source.chars().count(), target.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    let prefix = source
+        .chars()
+        .zip(target.chars())
+        .take_while(|(source_char, target_char)| source_char == target_char)
+        .count();
+    let distance = levenshtein_distance(source, target);
+    let score = ratio(distance, longest) + prefix_weight * (prefix as f64 / longest as f64);
+    score.min(1.0)
+}
+
+/// Converts a distance into a similarity ratio relative to the length of the longer string,
+/// which must not be 0.
+fn ratio(distance: usize, longest: usize) -> f64 {
+    1.0 - (distance as f64 / longest as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio() {
+        let result = similarity_ratio("rust", "rusty");
+        assert_eq!(result, 0.8);
+        let result = similarity_ratio("rusty", "rust");
+        assert_eq!(result, 0.8);
+    }
+
+    #[test]
+    fn identical_ratio() {
+        let result = similarity_ratio("kitten", "kitten");
+        assert_eq!(result, 1.0);
+        let result = similarity_ratio("", "");
+        assert_eq!(result, 1.0);
+    }
+
+    #[test]
+    fn unrelated_ratio() {
+        let result = similarity_ratio("unrelated", "SCREAMING");
+        assert_eq!(result, 0.0);
+        let result = similarity_ratio("bug", "");
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn percent() {
+        let result = similarity_percent("rust", "rusty");
+        assert_eq!(result, 80);
+        let result = similarity_percent("kitten", "sitting");
+        // 1 - 3/7 is 57.14...%
+        assert_eq!(result, 57);
+        // 1 - 1/8 is exactly 87.5%, which rounds up
+        let result = similarity_percent("abcdefgh", "abcdefgx");
+        assert_eq!(result, 88);
+    }
+
+    #[test]
+    fn percent_edges() {
+        let result = similarity_percent("rust", "rust");
+        assert_eq!(result, 100);
+        let result = similarity_percent("abc", "xyz");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn similar() {
+        assert!(is_similar("kitten", "sitting", 3));
+        assert!(!is_similar("kitten", "sitting", 2));
+        assert!(is_similar("", "", 0));
+        assert!(!is_similar("bug", "", 2));
+    }
+
+    #[test]
+    fn similar_ratio() {
+        assert!(is_similar_ratio("rust", "rusty", 0.8));
+        assert!(!is_similar_ratio("rust", "rusty", 0.81));
+        assert!(is_similar_ratio("", "", 1.0));
+        assert!(is_similar_ratio("abc", "xyz", 0.0));
+        assert!(is_similar_ratio("abc", "xyz", -1.0));
+        assert!(!is_similar_ratio("abc", "abc", 1.5));
+    }
+
+    #[test]
+    fn similar_ratio_same_as_ratio() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("rust", "trust"),
+            ("abcdefgh", "abcdefgx"),
+        ];
+        for (source, target) in pairs {
+            for percent in 0..=100 {
+                let min_ratio = percent as f64 / 100.0;
+                assert_eq!(
+                    is_similar_ratio(source, target, min_ratio),
+                    similarity_ratio(source, target) >= min_ratio
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn at_least_ratio() {
+        let pairs = [
+            ("rust", "rusty"),
+            ("kitten", "sitting"),
+            ("", ""),
+            ("bug", ""),
+            ("El delfín español", "Dolphin"),
+        ];
+        for (source, target) in pairs {
+            let ratio = similarity_ratio(source, target);
+            for min_ratio in [0.0, 0.25, 0.5, 0.57, 0.8, 0.9, 1.0] {
+                let expected = if ratio >= min_ratio {
+                    Some(ratio)
+                } else {
+                    None
+                };
+                assert_eq!(similarity_at_least(source, target, min_ratio), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn at_least_unrelated() {
+        let source = "a".repeat(1000);
+        let target = "b".repeat(1000);
+        let result = similarity_at_least(&source, &target, 0.8);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn combined_formula() {
+        let (source, target) = ("DWAYNE", "DUANE");
+        let expected = 0.5 * similarity_ratio(source, target)
+            + 0.25 * crate::jaro_winkler_similarity(source, target)
+            + 0.25 * crate::ngram_jaccard_similarity(source, target, 2);
+        let result = combined_similarity(source, target, SimilarityWeights::default());
+        assert!((result - expected).abs() < 1e-12);
+        let result = combined_similarity("abc", "xyz", SimilarityWeights::default());
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn combined_weights_normalized() {
+        let weights = SimilarityWeights {
+            levenshtein: 0.0,
+            jaro_winkler: 4.0,
+            ngram: 0.0,
+        };
+        let result = combined_similarity("DWAYNE", "DUANE", weights);
+        assert_eq!(result, crate::jaro_winkler_similarity("DWAYNE", "DUANE"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn combined_negative_weight() {
+        let weights = SimilarityWeights {
+            levenshtein: 1.0,
+            jaro_winkler: -1.0,
+            ngram: 1.0,
+        };
+        combined_similarity("a", "b", weights);
+    }
+
+    #[test]
+    fn prefix_bonus() {
+        let result = prefix_similarity("auto", "automobile", 0.5);
+        assert_eq!(result, 0.4 + 0.5 * 0.4);
+        let result = prefix_similarity("auto", "semiauto", 0.5);
+        assert_eq!(result, similarity_ratio("auto", "semiauto"));
+        // No weight is the same as the similarity ratio
+        let result = prefix_similarity("auto", "automobile", 0.0);
+        assert_eq!(result, similarity_ratio("auto", "automobile"));
+    }
+
+    #[test]
+    fn prefix_clamped() {
+        assert_eq!(prefix_similarity("rust", "rust", 0.5), 1.0);
+        assert_eq!(prefix_similarity("rust", "rusty", 1.0), 1.0);
+        assert_eq!(prefix_similarity("", "", 1.0), 1.0);
+        assert_eq!(prefix_similarity("", "rust", 1.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn prefix_negative_weight() {
+        prefix_similarity("rust", "rusty", -0.5);
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+
+use unicode_security::skeleton;
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string to the
+/// target string after replacing every character in both of them with its confusable skeleton,
+/// so characters which look the same are treated as equal.
+///
+/// Many characters from different scripts are visually identical or nearly so, such as the
+/// Latin "a" and the Cyrillic "а", which lets a spoofed name like "раypal" pass for "paypal".
+/// Comparing skeletons catches these homoglyphs, as lookalike characters share a skeleton.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, levenshtein_distance_skeleton};
+/// // The first two letters are the Cyrillic р and а
+/// let spoofed = "\u{440}\u{430}ypal";
+/// assert_eq!(2, levenshtein_distance(spoofed, "paypal"));
+/// assert_eq!(0, levenshtein_distance_skeleton(spoofed, "paypal"));
+/// ```
+///
+/// The skeleton of a string is defined by the confusable detection section of
+/// [Unicode Technical Standard #39](https://www.unicode.org/reports/tr39/#Confusable_Detection):
+/// the string is decomposed to NFD, each character is replaced by its prototype from the
+/// Unicode [confusables.txt](https://www.unicode.org/Public/security/latest/confusables.txt)
+/// data file, and the result is decomposed to NFD again. The mapping comes from the
+/// [unicode-security](https://docs.rs/unicode-security) crate's copy of that data file.
+///
+/// Skeletons are only meant for comparing strings, not for displaying. They don't preserve
+/// case, as letters such as "l", "I" and "1" share a skeleton, and a character can map to more
+/// than one character, such as "m" which shares a skeleton with "rn", so the distance is counted
+/// in the characters of the skeletons rather than of the original strings.
+///
+/// This is only available with the `unicode-security` feature enabled.
+///
+/// See also:
+/// - [IDN homograph attack](https://en.wikipedia.org/wiki/IDN_homograph_attack)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_skeleton(source: &str, target: &str) -> usize {
+    let source = skeleton(source).collect::<Vec<char>>();
+    let target = skeleton(target).collect::<Vec<char>>();
+    crate::levenshtein_distance_slice(&source, &target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cyrillic_lookalikes() {
+        // Cyrillic а, е and о
+        let result = levenshtein_distance_skeleton("\u{430}ppl\u{435}", "apple");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_skeleton("g\u{43e}\u{43e}gle", "google");
+        assert_eq!(result, 0);
+        assert_eq!(
+            crate::levenshtein_distance("g\u{43e}\u{43e}gle", "google"),
+            2
+        );
+    }
+
+    #[test]
+    fn lookalike_digits() {
+        let result = levenshtein_distance_skeleton("paypa1", "paypal");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_skeleton("g00gle", "gOOgle");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn different_letters() {
+        let result = levenshtein_distance_skeleton("paypal", "paypals");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_skeleton("paypal", "eBay");
+        assert_eq!(result, crate::levenshtein_distance("paypal", "eBay"));
+        // m shares a skeleton with rn, so has two characters in its skeleton
+        let result = levenshtein_distance_skeleton("modern", "rnodern");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_skeleton("m", "");
+        assert_eq!(result, 2);
+        let result = levenshtein_distance_skeleton("", "rust");
+        assert_eq!(result, 4);
+    }
+}
@@ -0,0 +1,67 @@
+use tinyvec::TinyVec;
+
+/// The number of elements kept on the stack before a buffer spills over onto the heap.
+const INLINE: usize = 32;
+
+/// The implementation of [levenshtein_distance](crate::levenshtein_distance) which keeps the
+/// characters of its inputs and the row of edit distances on the stack when the inputs are
+/// short enough, only allocating for longer inputs.
+pub(crate) fn levenshtein_distance_small(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<TinyVec<[char; INLINE]>>();
+    let target = target.chars().collect::<TinyVec<[char; INLINE]>>();
+    levenshtein_distance_slice_small(&source, &target)
+}
+
+/// The implementation of [levenshtein_distance_slice](crate::levenshtein_distance_slice) which
+/// keeps the row of edit distances on the stack when the shorter input is short enough, only
+/// allocating for longer inputs.
+pub(crate) fn levenshtein_distance_slice_small<T: PartialEq>(source: &[T], target: &[T]) -> usize {
+    levenshtein_distance_slice_small_by(source, target, |s, t| s == t)
+}
+
+/// The implementation of [levenshtein_distance_slice_by](crate::levenshtein_distance_slice_by)
+/// which keeps the row of edit distances on the stack when the shorter input is short enough,
+/// only allocating for longer inputs.
+pub(crate) fn levenshtein_distance_slice_small_by<T, F>(source: &[T], target: &[T], eq: F) -> usize
+where
+    F: Fn(&T, &T) -> bool,
+{
+    // The row is sized by the shorter input, so only that one needs to fit for us to avoid
+    // allocating
+    let mut edit_distances = TinyVec::<[usize; INLINE + 1]>::new();
+    edit_distances.resize(core::cmp::min(source.len(), target.len()) + 1, 0);
+    crate::levenshtein_distance_in_row(source, target, &mut edit_distances, eq)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Levenshtein;
+
+    #[test]
+    fn same_as_heap() {
+        let short = "kitten";
+        let long = "a much longer string than fits in the inline buffers";
+        let pairs = [
+            (short, "sitting"),
+            ("", short),
+            (short, long),
+            (long, short),
+            (long, "a much shorter string"),
+            ("El delfín español", "Dolphin"),
+        ];
+        for (source, target) in pairs {
+            let expected = Levenshtein::new().distance(source, target);
+            assert_eq!(levenshtein_distance_small(source, target), expected);
+        }
+    }
+
+    #[test]
+    fn slices() {
+        let source = [1, 2, 3, 4];
+        let target = [2, 3, 4, 5];
+        assert_eq!(levenshtein_distance_slice_small(&source, &target), 2);
+        let long = [0; 100];
+        assert_eq!(levenshtein_distance_slice_small(&long, &long[50..]), 50);
+    }
+}
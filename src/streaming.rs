@@ -0,0 +1,130 @@
+use alloc::vec::Vec;
+
+/// A Levenshtein distance calculation where the target string is fed in a piece at a time, so
+/// the whole target never needs to be in memory at once.
+///
+/// The source string is given up front, and then the target can be pushed in chunks of any
+/// size, such as the lines of a document as they are read from a file. The result of
+/// [StreamingLevenshtein::finish] is the same as the
+/// [levenshtein_distance](crate::levenshtein_distance) from the source to all of the chunks
+/// joined together.
+///
+/// ```
+/// use fuzzy_string_distance::StreamingLevenshtein;
+/// let mut streaming = StreamingLevenshtein::new("kitten");
+/// streaming.push_chars("sit");
+/// streaming.push_chars("");
+/// streaming.push_chars("ting");
+/// assert_eq!(3, streaming.finish());
+/// ```
+///
+/// Only the characters of the source and a single row of edit distances, one entry longer than
+/// the source, are stored between chunks, so the source must fit in memory but the target can
+/// be as long as you like. Each chunk takes time proportional to its length times the length of
+/// the source.
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StreamingLevenshtein {
+    source: Vec<char>,
+    /// The edit distances from each prefix of the source to the target pushed so far
+    edit_distances: Vec<usize>,
+    /// The number of target characters pushed so far
+    target_chars: usize,
+}
+
+impl StreamingLevenshtein {
+    /// Creates a new StreamingLevenshtein comparing against the source string, with an empty
+    /// target.
+    pub fn new(source: &str) -> Self {
+        let source = source.chars().collect::<Vec<char>>();
+        // Converting each prefix of the source to an empty target is purely deletions
+        let edit_distances = (0..=source.len()).collect();
+        StreamingLevenshtein {
+            source,
+            edit_distances,
+            target_chars: 0,
+        }
+    }
+
+    /// Appends the chunk to the end of the target string pushed so far.
+    pub fn push_chars(&mut self, chunk: &str) {
+        for target_char in chunk.chars() {
+            self.push_char(target_char);
+        }
+    }
+
+    /// Appends a single character to the end of the target string pushed so far.
+    pub fn push_char(&mut self, target_char: char) {
+        // This is the same recurrence as levenshtein_distance with the roles of the rows and
+        // columns swapped, as each row of the matrix here is for a prefix of the target
+        self.target_chars += 1;
+        let mut previous_diagonal = self.edit_distances[0];
+        self.edit_distances[0] = self.target_chars;
+        for (i, source_char) in self.source.iter().enumerate() {
+            let above = self.edit_distances[i + 1];
+            let substitution = if *source_char == target_char {
+                previous_diagonal
+            } else {
+                previous_diagonal + 1
+            };
+            self.edit_distances[i + 1] = core::cmp::min(
+                substitution,
+                core::cmp::min(above, self.edit_distances[i]) + 1,
+            );
+            previous_diagonal = above;
+        }
+    }
+
+    /// Returns the distance from the source string to the target string pushed so far, without
+    /// finishing, so more of the target can still be pushed afterwards.
+    pub fn distance(&self) -> usize {
+        self.edit_distances[self.source.len()]
+    }
+
+    /// Returns the distance from the source string to the whole target string that was pushed.
+    pub fn finish(self) -> usize {
+        self.distance()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein_distance;
+
+    #[test]
+    fn chunks_same_as_whole() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("", "rust"),
+            ("bug", ""),
+            ("El delfín español", "Dolphin"),
+        ];
+        for (source, target) in pairs {
+            let expected = levenshtein_distance(source, target);
+            for chunk_size in 1..=3 {
+                let mut streaming = StreamingLevenshtein::new(source);
+                let chars = target.chars().collect::<Vec<char>>();
+                for chunk in chars.chunks(chunk_size) {
+                    streaming.push_chars(&chunk.iter().collect::<String>());
+                }
+                assert_eq!(streaming.finish(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn distance_so_far() {
+        let mut streaming = StreamingLevenshtein::new("rust");
+        assert_eq!(streaming.distance(), 4);
+        streaming.push_chars("ru");
+        assert_eq!(streaming.distance(), 2);
+        streaming.push_chars("st");
+        assert_eq!(streaming.distance(), 0);
+        streaming.push_char('y');
+        assert_eq!(streaming.finish(), 1);
+    }
+}
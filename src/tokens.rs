@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+
+use crate::{levenshtein_distance_buffered_by, levenshtein_distance_slice};
+
+/// Returns the minimum number of single token insertions, deletions or substitutions required
+/// to convert the source string to the target string, where the strings are split into tokens
+/// on the separator, or on whitespace if the separator is `None`.
+///
+/// This is the [levenshtein_distance_slice] over the tokens of each string, so a whole word
+/// being different only counts as one edit, rather than as many edits as there are characters
+/// in the word. Tokens are compared for exact equality.
+///
+/// ```
+/// use fuzzy_string_distance::token_levenshtein_distance;
+/// assert_eq!(1, token_levenshtein_distance("the quick fox", "the slow fox", None));
+/// assert_eq!(2, token_levenshtein_distance("a,b,c,d", "a,c,d,e", Some(',')));
+/// ```
+///
+/// Empty tokens are ignored, so consecutive separators or separators at the start or end of
+/// the string do not affect the distance, in the same way that [str::split_whitespace] ignores
+/// runs of whitespace.
+///
+/// ```
+/// use fuzzy_string_distance::token_levenshtein_distance;
+/// assert_eq!(0, token_levenshtein_distance("a,,b,", "a,b", Some(',')));
+/// assert_eq!(0, token_levenshtein_distance("  the   quick fox", "the quick fox", None));
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+pub fn token_levenshtein_distance(source: &str, target: &str, separator: Option<char>) -> usize {
+    let source = tokenize(source, separator);
+    let target = tokenize(target, separator);
+    levenshtein_distance_slice(&source, &target)
+}
+
+/// Returns the minimum number of single token insertions, deletions or substitutions required
+/// to convert the source tokens to the target tokens, where `token_eq` decides if two tokens are
+/// equal.
+///
+/// This allows tokens to be compared fuzzily, for instance by treating tokens with a small
+/// [levenshtein_distance](crate::levenshtein_distance) between them as equal, so that typos
+/// within words are absorbed and only whole words being different count as edits.
+/// `token_eq` is called with a token from the source first and a token from the target second.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, token_levenshtein_with};
+/// let source = ["my", "favourite", "colour"];
+/// let target = ["my", "favorite", "color", "scheme"];
+/// let typo = |a: &str, b: &str| levenshtein_distance(a, b) <= 1;
+/// assert_eq!(1, token_levenshtein_with(&source, &target, typo)); // insert scheme
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+pub fn token_levenshtein_with<F>(source: &[&str], target: &[&str], token_eq: F) -> usize
+where
+    F: Fn(&str, &str) -> bool,
+{
+    levenshtein_distance_buffered_by(source, target, &mut Vec::new(), |s, t| token_eq(s, t))
+}
+
+/// Splits the string into its non empty tokens on the separator, or on whitespace if the
+/// separator is `None`.
+fn tokenize(string: &str, separator: Option<char>) -> Vec<&str> {
+    match separator {
+        Some(separator) => string
+            .split(separator)
+            .filter(|token| !token.is_empty())
+            .collect(),
+        None => string.split_whitespace().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_word_different() {
+        let result = token_levenshtein_distance("the quick fox", "the slow fox", None);
+        assert_eq!(result, 1);
+        let result = token_levenshtein_distance("the quick brown fox", "the fox", None);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn separators() {
+        let result = token_levenshtein_distance("a,b,c,d", "a,c,d,e", Some(','));
+        assert_eq!(result, 2);
+        // Spaces are part of the tokens when splitting on commas
+        let result = token_levenshtein_distance("a, b", "a,b", Some(','));
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn empty_tokens() {
+        let result = token_levenshtein_distance(",,a,,b,,", "a,b", Some(','));
+        assert_eq!(result, 0);
+        let result = token_levenshtein_distance("", "the quick fox", None);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn fuzzy_tokens() {
+        let typo = |a: &str, b: &str| crate::levenshtein_distance(a, b) <= 1;
+        let result = token_levenshtein_with(&["color"], &["colour"], typo);
+        assert_eq!(result, 0);
+        let result = token_levenshtein_with(&["colour"], &["flavour"], typo);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn exact_tokens() {
+        let source = ["the", "quick", "fox"];
+        let target = ["the", "slow", "fox"];
+        let result = token_levenshtein_with(&source, &target, |a, b| a == b);
+        assert_eq!(result, 1);
+    }
+}
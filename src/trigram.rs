@@ -0,0 +1,174 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// An index from each character trigram to the strings containing it, for quickly narrowing
+/// down a large collection of strings to the few that could be close to a query.
+///
+/// A trigram is a run of 3 consecutive characters, so the trigrams of "rusty" are "rus", "ust"
+/// and "sty". Strings which are only a few edits apart share most of their trigrams, so only
+/// running the much more expensive [levenshtein_distance](crate::levenshtein_distance) on the
+/// strings which share enough trigrams with the query avoids comparing the query against
+/// every string in the collection.
+///
+/// Each string is given an id when it is inserted, counting up from 0 in insertion order.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance, TrigramIndex};
+/// let index = ["install", "uninstall", "update", "list"]
+///     .into_iter()
+///     .map(String::from)
+///     .collect::<TrigramIndex>();
+/// let candidates = index.candidates("instal", 3);
+/// assert_eq!(vec![0, 1], candidates);
+/// let closest = candidates
+///     .into_iter()
+///     .filter_map(|id| index.get(id))
+///     .min_by_key(|candidate| levenshtein_distance("instal", candidate));
+/// assert_eq!(Some("install"), closest);
+/// ```
+///
+/// Strings are not padded at the start or end, so a string with fewer than 3 characters has no
+/// trigrams and is never a candidate unless `min_shared` is 0.
+///
+/// See also:
+/// - [Trigram search](https://en.wikipedia.org/wiki/Trigram_search)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+#[derive(Clone, Debug, Default)]
+pub struct TrigramIndex {
+    strings: Vec<String>,
+    /// The ids of the strings containing each trigram, in ascending order
+    trigrams: BTreeMap<[char; 3], Vec<usize>>,
+}
+
+impl TrigramIndex {
+    /// Creates a new empty TrigramIndex.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of strings in the index.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if there are no strings in the index.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Adds a string to the index, returning its id.
+    pub fn insert(&mut self, string: String) -> usize {
+        let id = self.strings.len();
+        for trigram in trigrams(&string) {
+            // Ids only ever go up, so pushing keeps each list in ascending order
+            self.trigrams.entry(trigram).or_default().push(id);
+        }
+        self.strings.push(string);
+        id
+    }
+
+    /// Returns the string with the given id, or `None` if there is no string with that id.
+    pub fn get(&self, id: usize) -> Option<&str> {
+        self.strings.get(id).map(String::as_str)
+    }
+
+    /// Returns the ids of every string which shares at least `min_shared` distinct trigrams with
+    /// the query, in ascending order.
+    pub fn candidates(&self, query: &str, min_shared: usize) -> Vec<usize> {
+        let mut shared = vec![0; self.strings.len()];
+        for trigram in trigrams(query) {
+            if let Some(ids) = self.trigrams.get(&trigram) {
+                for &id in ids {
+                    shared[id] += 1;
+                }
+            }
+        }
+        shared
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, shared)| shared >= min_shared)
+            .map(|(id, _)| id)
+            .collect()
+    }
+}
+
+/// Returns the distinct trigrams in the string.
+fn trigrams(string: &str) -> BTreeSet<[char; 3]> {
+    let chars = string.chars().collect::<Vec<char>>();
+    chars
+        .windows(3)
+        .map(|window| [window[0], window[1], window[2]])
+        .collect()
+}
+
+impl Extend<String> for TrigramIndex {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, strings: I) {
+        for string in strings {
+            self.insert(string);
+        }
+    }
+}
+
+impl FromIterator<String> for TrigramIndex {
+    fn from_iter<I: IntoIterator<Item = String>>(strings: I) -> Self {
+        let mut index = TrigramIndex::new();
+        index.extend(strings);
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> TrigramIndex {
+        ["rust", "trust", "rusty", "crust", "dusty", "typography"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn candidates() {
+        let index = index();
+        // rus and ust
+        let result = index.candidates("rust", 2);
+        assert_eq!(result, vec![0, 1, 2, 3]);
+        // ust is in every string but typography
+        let result = index.candidates("rust", 1);
+        assert_eq!(result, vec![0, 1, 2, 3, 4]);
+        let result = index.candidates("rusty", 3);
+        assert_eq!(result, vec![2]);
+    }
+
+    #[test]
+    fn repeated_trigrams() {
+        let mut index = TrigramIndex::new();
+        assert!(index.is_empty());
+        assert_eq!(index.insert("aaaa".to_string()), 0);
+        assert_eq!(index.insert("aaab".to_string()), 1);
+        // aaa is only counted once even though it appears twice in the query
+        let result = index.candidates("aaaaa", 2);
+        assert_eq!(result, Vec::<usize>::new());
+        let result = index.candidates("aaaaa", 1);
+        assert_eq!(result, vec![0, 1]);
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.get(1), Some("aaab"));
+        assert_eq!(index.get(2), None);
+    }
+
+    #[test]
+    fn short_strings() {
+        let mut index = index();
+        index.insert("ru".to_string());
+        let result = index.candidates("ru", 1);
+        assert_eq!(result, Vec::<usize>::new());
+        let result = index.candidates("ru", 0);
+        assert_eq!(result, vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+}
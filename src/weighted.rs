@@ -0,0 +1,652 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The cost of each kind of single character edit, for use with
+/// [levenshtein_distance_weighted].
+///
+/// The default costs are 1 for every operation, which gives the same results as
+/// [levenshtein_distance](crate::levenshtein_distance).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Costs {
+    /// The cost of inserting a character into the source
+    pub insert: usize,
+    /// The cost of deleting a character from the source
+    pub delete: usize,
+    /// The cost of substituting a character in the source for a different character
+    pub substitute: usize,
+}
+
+impl Default for Costs {
+    fn default() -> Self {
+        Costs {
+            insert: 1,
+            delete: 1,
+            substitute: 1,
+        }
+    }
+}
+
+/// Returns the minimum total cost of single character insertions, deletions or substitutions
+/// required to convert the source string to the target string, where each kind of edit has the
+/// cost given in `costs`.
+///
+/// With all costs set to 1 this is the same as
+/// [levenshtein_distance](crate::levenshtein_distance). If insertions and deletions have
+/// different costs then the distance is no longer symmetric, as converting the target back to the
+/// source swaps every insertion for a deletion and vice versa.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance_weighted, Costs};
+/// let costs = Costs { insert: 1, delete: 5, substitute: 1 };
+/// assert_eq!(1, levenshtein_distance_weighted("cat", "cart", costs)); // insert r
+/// assert_eq!(5, levenshtein_distance_weighted("cart", "cat", costs)); // delete r
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_weighted(source: &str, target: &str, costs: Costs) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    weighted_distance(
+        &source,
+        &target,
+        costs.insert,
+        costs.delete,
+        |source_char, target_char| {
+            if source_char == target_char {
+                0
+            } else {
+                costs.substitute
+            }
+        },
+    )
+}
+
+/// A modified [levenshtein_distance_weighted] that matches from the source string to an
+/// arbitrary substring of the target string, returning the minimum total cost of single
+/// character insertions, deletions or substitutions required to convert the source string to
+/// match any substring in the target, where each kind of edit has the cost given in `costs`.
+///
+/// As in [local_levenshtein_distance](crate::local_levenshtein_distance), starting or ending the
+/// match anywhere in the target is free, so only edits within the matched substring count.
+/// Insertions add characters of the target that are missing from the query, and deletions drop
+/// characters of the query that aren't in the target, so making deletions more expensive
+/// penalises queries with extra characters more than queries with missing characters.
+///
+/// ```
+/// use fuzzy_string_distance::{local_levenshtein_distance_weighted, Costs};
+/// let costs = Costs { insert: 1, delete: 3, substitute: 1 };
+/// // insert o
+/// assert_eq!(1, local_levenshtein_distance_weighted("lng", "A long sentence", costs));
+/// // delete the extra g
+/// assert_eq!(3, local_levenshtein_distance_weighted("longg", "long", costs));
+/// ```
+///
+/// With all costs set to 1 this is the same as
+/// [local_levenshtein_distance](crate::local_levenshtein_distance).
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+/// - [Fuzzy Substring Matching: On-device Fuzzy Friend Search at Snapchat](http://arxiv.org/pdf/2211.02767)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn local_levenshtein_distance_weighted(source: &str, target: &str, costs: Costs) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let target_chars = target.len();
+
+    // The first row is all zeros rather than insertions, as we don't penalise starting the
+    // match anywhere in the target
+    let mut edit_distances = vec![0; target_chars + 1];
+    let mut new_edit_distances = vec![0; target_chars + 1];
+
+    for (i, source_char) in source.iter().enumerate() {
+        // Matching the i+1 prefix of `source` against an empty substring is purely deletions
+        new_edit_distances[0] = (i + 1) * costs.delete;
+
+        for (j, target_char) in target.iter().enumerate() {
+            let deletion = edit_distances[j + 1] + costs.delete;
+            let insertion = new_edit_distances[j] + costs.insert;
+            let substitution = if source_char == target_char {
+                edit_distances[j]
+            } else {
+                edit_distances[j] + costs.substitute
+            };
+            new_edit_distances[j + 1] =
+                core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+        }
+
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+
+    // The match can end anywhere in the target too, so take the cheapest of the final row
+    edit_distances.into_iter().min().unwrap_or(0)
+}
+
+/// Returns the minimum total cost of single character insertions, deletions or substitutions
+/// required to convert the source string to the target string, where the cost of substituting
+/// one character for another is given by `substitution_cost`.
+///
+/// Insertions and deletions always cost 1, see [levenshtein_distance_weighted] to change those.
+/// `substitution_cost` is called with the source character and the target character for every
+/// pair of characters compared, including pairs of equal characters, and should return 0 when
+/// the characters are equal. Since costs are `usize` they can never be negative, and a
+/// substitution costing more than 2 will never be chosen as it is cheaper to delete and then
+/// insert instead.
+///
+/// This can model typos more closely than uniform costs, for instance by making substitutions
+/// between neighbouring keys on a keyboard cheaper than substitutions between distant keys.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_with;
+/// // Treat vowels as interchangeable
+/// let is_vowel = |c: char| "aeiou".contains(c);
+/// let cost = |a: char, b: char| {
+///     if a == b || (is_vowel(a) && is_vowel(b)) { 0 } else { 1 }
+/// };
+/// assert_eq!(0, levenshtein_distance_with("colour", "coloor", cost));
+/// assert_eq!(1, levenshtein_distance_with("colour", "colour!", cost));
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_with<F>(source: &str, target: &str, substitution_cost: F) -> usize
+where
+    F: Fn(char, char) -> usize,
+{
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    weighted_distance(&source, &target, 1, 1, substitution_cost)
+}
+
+/// Returns the [levenshtein_distance](crate::levenshtein_distance) from the source string to the
+/// target string, where substituting a character for another in the same class is free.
+///
+/// `classify` maps each character to the id of its class, and any two characters with the
+/// same id are treated as equal. This can make all digits interchangeable, or all kinds of
+/// whitespace, so strings with the same shape are close even if their details differ.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_with_classes;
+/// let digits = |c: char| if c.is_ascii_digit() { 0 } else { u32::from(c) + 1 };
+/// assert_eq!(0, levenshtein_distance_with_classes("v1.2", "v9.8", digits));
+/// // The digits still have to be inserted
+/// assert_eq!(2, levenshtein_distance_with_classes("v1.2", "v10.20", digits));
+/// ```
+///
+/// This differs from removing the characters before comparing, as with
+/// [levenshtein_distance_ignoring](crate::levenshtein_distance_ignoring), since characters in
+/// the same class only match each other. Inserting or deleting them still costs 1 as normal, so
+/// "v1" and "v10" are 1 edit apart rather than 0. Every character should map to the same class
+/// each time `classify` is called with it, and characters which shouldn't match anything
+/// else need a class of their own.
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_with_classes<F>(source: &str, target: &str, classify: F) -> usize
+where
+    F: Fn(char) -> u32,
+{
+    // Classify each character once up front rather than every time it is compared
+    let source = source.chars().map(&classify).collect::<Vec<u32>>();
+    let target = target.chars().map(&classify).collect::<Vec<u32>>();
+    crate::levenshtein_distance_slice(&source, &target)
+}
+
+/// Returns the minimum total cost of single character insertions, deletions or substitutions
+/// required to convert the source string to the target string, where edits near the start of
+/// the strings cost more than edits near the end.
+///
+/// Typos are rarer at the start of a word than at the end, so a difference at the start is more
+/// likely to mean the strings are different words. Each edit costs `decay` raised to the power
+/// of its position, so with a decay of 0.5 an edit to the first character costs 1.0, an edit to
+/// the second character costs 0.5, an edit to the third costs 0.25, and so on.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_prefix_weighted;
+/// // Substituting the first character
+/// assert_eq!(1.0, levenshtein_distance_prefix_weighted("bat", "cat", 0.5));
+/// // Substituting the last character
+/// assert_eq!(0.25, levenshtein_distance_prefix_weighted("cab", "cat", 0.5));
+/// ```
+///
+/// The position of an edit is the index of the character it edits, counting from 0, which is
+/// the index in the source string for deletions and substitutions, and the index in the target
+/// string for insertions. Precisely, the cost of deleting `source[i]` is `decay^i`, the cost of
+/// inserting `target[j]` is `decay^j`, and the cost of substituting `source[i]` for a different
+/// `target[j]` is `decay^min(i, j)`. The distance is the smallest total cost of any sequence of
+/// edits, as in [levenshtein_distance](crate::levenshtein_distance). A decay of 1.0 makes every
+/// edit cost 1.0, which gives the same result as
+/// [levenshtein_distance](crate::levenshtein_distance).
+///
+/// # Panics
+///
+/// If `decay` is not greater than 0.0 and at most 1.0.
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_prefix_weighted(source: &str, target: &str, decay: f64) -> f64 {
+    assert!(
+        decay > 0.0 && decay <= 1.0,
+        "decay must be greater than 0 and at most 1"
+    );
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    let target_chars = target.len();
+
+    // The cost of an edit at each position, built up by repeated multiplication so we don't
+    // need floating point powers, which aren't available without std
+    let mut costs = Vec::with_capacity(core::cmp::max(source.len(), target_chars));
+    let mut cost = 1.0;
+    for _ in 0..core::cmp::max(source.len(), target_chars) {
+        costs.push(cost);
+        cost *= decay;
+    }
+
+    // The same rows as Levenshtein distance, but each edit adds the cost of its position. We
+    // can't swap the inputs around as insertions are weighted by their position in the target
+    // and deletions by their position in the source.
+    let mut edit_distances = Vec::with_capacity(target_chars + 1);
+    edit_distances.push(0.0);
+    for j in 0..target_chars {
+        edit_distances.push(edit_distances[j] + costs[j]);
+    }
+    let mut new_edit_distances = vec![0.0; target_chars + 1];
+
+    for (i, source_char) in source.iter().enumerate() {
+        new_edit_distances[0] = edit_distances[0] + costs[i];
+
+        for (j, target_char) in target.iter().enumerate() {
+            let deletion = edit_distances[j + 1] + costs[i];
+            let insertion = new_edit_distances[j] + costs[j];
+            let substitution = if source_char == target_char {
+                edit_distances[j]
+            } else {
+                edit_distances[j] + costs[core::cmp::min(i, j)]
+            };
+            new_edit_distances[j + 1] = deletion.min(insertion).min(substitution);
+        }
+
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+
+    edit_distances[target_chars]
+}
+
+/// A table of the cost of substituting one character for another, for use with
+/// [levenshtein_distance_with_matrix].
+///
+/// Any pair of different characters which hasn't been given a cost with [CostMatrix::insert]
+/// costs the default substitution cost the matrix was created with, and substituting a
+/// character for itself always costs 0.
+///
+/// By default the costs are symmetric, so setting the cost of substituting x for y also sets the
+/// cost of substituting y for x. Create the matrix with [CostMatrix::new_asymmetric] to set the
+/// cost of each direction separately.
+///
+/// ```
+/// use fuzzy_string_distance::CostMatrix;
+/// let mut matrix = CostMatrix::new(2);
+/// matrix.insert('a', 'e', 1);
+/// assert_eq!(1, matrix.cost('e', 'a'));
+/// assert_eq!(2, matrix.cost('a', 'z')); // not listed, so the default cost
+/// assert_eq!(0, matrix.cost('a', 'a'));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CostMatrix {
+    default_cost: usize,
+    symmetric: bool,
+    costs: BTreeMap<(char, char), usize>,
+}
+
+impl CostMatrix {
+    /// Creates a new symmetric CostMatrix where every substitution costs `default_cost` until
+    /// given a different cost.
+    pub fn new(default_cost: usize) -> Self {
+        CostMatrix {
+            default_cost,
+            symmetric: true,
+            costs: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a new asymmetric CostMatrix where every substitution costs `default_cost` until
+    /// given a different cost, and substituting x for y can cost a different amount to
+    /// substituting y for x.
+    pub fn new_asymmetric(default_cost: usize) -> Self {
+        CostMatrix {
+            symmetric: false,
+            ..CostMatrix::new(default_cost)
+        }
+    }
+
+    /// Sets the cost of substituting the source character for the target character, replacing
+    /// any cost already set for this pair. If the matrix is symmetric this also sets the cost of
+    /// substituting the target character for the source character.
+    ///
+    /// Setting the cost of substituting a character for itself has no effect, as that is always
+    /// 0.
+    pub fn insert(&mut self, source: char, target: char, cost: usize) {
+        self.costs.insert((source, target), cost);
+        if self.symmetric {
+            self.costs.insert((target, source), cost);
+        }
+    }
+
+    /// Returns the cost of substituting the source character for the target character, which
+    /// is 0 if they are the same character or the default cost if the pair hasn't been given a
+    /// cost.
+    pub fn cost(&self, source: char, target: char) -> usize {
+        if source == target {
+            0
+        } else {
+            self.costs
+                .get(&(source, target))
+                .copied()
+                .unwrap_or(self.default_cost)
+        }
+    }
+
+    /// Returns the cost of substituting any pair of different characters which hasn't been
+    /// given a cost.
+    pub fn default_cost(&self) -> usize {
+        self.default_cost
+    }
+
+    /// Returns true if the cost of substituting x for y is always the same as substituting y
+    /// for x.
+    pub fn is_symmetric(&self) -> bool {
+        self.symmetric
+    }
+}
+
+/// Returns the minimum total cost of single character insertions, deletions or substitutions
+/// required to convert the source string to the target string, where the cost of substituting
+/// one character for another is looked up in the [CostMatrix].
+///
+/// Insertions and deletions always cost 1, and substitutions of pairs which haven't been given
+/// a cost in the matrix fall back to its default cost. As with [levenshtein_distance_with], a
+/// substitution costing more than 2 will never be chosen as it is cheaper to delete and then
+/// insert instead.
+///
+/// ```
+/// use fuzzy_string_distance::{levenshtein_distance_with_matrix, CostMatrix};
+/// let mut matrix = CostMatrix::new(2);
+/// matrix.insert('s', 'z', 1);
+/// assert_eq!(1, levenshtein_distance_with_matrix("realise", "realize", &matrix));
+/// assert_eq!(2, levenshtein_distance_with_matrix("realise", "realite", &matrix));
+/// ```
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_with_matrix(source: &str, target: &str, matrix: &CostMatrix) -> usize {
+    let source = source.chars().collect::<Vec<char>>();
+    let target = target.chars().collect::<Vec<char>>();
+    weighted_distance(&source, &target, 1, 1, |source_char, target_char| {
+        matrix.cost(source_char, target_char)
+    })
+}
+
+/// The implementation of Levenshtein distance with weighted edits, where the cost of
+/// substituting one character for another is given by a function so callers can vary it per
+/// pair of characters.
+pub(crate) fn weighted_distance<F>(
+    source: &[char],
+    target: &[char],
+    insert: usize,
+    delete: usize,
+    substitute: F,
+) -> usize
+where
+    F: Fn(char, char) -> usize,
+{
+    let target_chars = target.len();
+    // We'll have the same matrix A of `source` length + 1 rows and `target` length + 1 columns
+    // as Levenshtein distance, but each edit we make adds its own cost instead of 1.
+    // We can't swap the inputs around to minimise the buffer size as we do for Levenshtein
+    // distance, since that would swap insertions and deletions.
+
+    // First row of edit distances are converting an empty string `source` to prefixes of
+    // the `target`, which is purely insertions.
+    let mut edit_distances = (0..=target_chars)
+        .map(|j| j * insert)
+        .collect::<Vec<usize>>();
+    let mut new_edit_distances = vec![0; target_chars + 1];
+
+    for (i, source_char) in source.iter().enumerate() {
+        // Converting the i+1 prefix of `source` to an empty string is purely deletions
+        new_edit_distances[0] = (i + 1) * delete;
+
+        for (j, target_char) in target.iter().enumerate() {
+            // We're calculating the edit distance for A[i + 1, j + 1] from the same three
+            // options as Levenshtein distance, only with different costs for each
+            let deletion = edit_distances[j + 1] + delete;
+            let insertion = new_edit_distances[j] + insert;
+            let substitution = edit_distances[j] + substitute(*source_char, *target_char);
+            new_edit_distances[j + 1] =
+                core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+        }
+
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+
+    edit_distances[target_chars]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_costs() {
+        let result = levenshtein_distance_weighted("kitten", "sitting", Costs::default());
+        assert_eq!(result, 3);
+        let result = levenshtein_distance_weighted("", "rust", Costs::default());
+        assert_eq!(result, 4);
+        let result = levenshtein_distance_weighted("bug", "", Costs::default());
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn expensive_deletions() {
+        let costs = Costs {
+            insert: 1,
+            delete: 5,
+            substitute: 1,
+        };
+        let result = levenshtein_distance_weighted("cat", "cart", costs);
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_weighted("cart", "cat", costs);
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn expensive_substitutions() {
+        // Substituting is more expensive than deleting and then inserting, so we never
+        // substitute
+        let costs = Costs {
+            insert: 1,
+            delete: 1,
+            substitute: 3,
+        };
+        let result = levenshtein_distance_weighted("kitten", "sitting", costs);
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn substitution_function() {
+        // Neighbouring keys on the home row are cheaper to substitute
+        let home_row = "asdfghjkl";
+        let cost = |a: char, b: char| {
+            if a == b {
+                0
+            } else {
+                match (home_row.find(a), home_row.find(b)) {
+                    (Some(x), Some(y)) if x.abs_diff(y) == 1 => 1,
+                    _ => 2,
+                }
+            }
+        };
+        let result = levenshtein_distance_with("flask", "glask", cost);
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_with("flask", "plask", cost);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn uniform_substitution_function() {
+        let cost = |a: char, b: char| if a == b { 0 } else { 1 };
+        let result = levenshtein_distance_with("kitten", "sitting", cost);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn cost_matrix_fallback() {
+        let mut matrix = CostMatrix::new(2);
+        matrix.insert('s', 'z', 1);
+        let result = levenshtein_distance_with_matrix("realise", "realize", &matrix);
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_with_matrix("realize", "realise", &matrix);
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_with_matrix("realise", "realite", &matrix);
+        assert_eq!(result, 2);
+        // A default cost of 1 with no pairs is the same as Levenshtein distance
+        let result = levenshtein_distance_with_matrix("kitten", "sitting", &CostMatrix::new(1));
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn asymmetric_cost_matrix() {
+        let mut matrix = CostMatrix::new_asymmetric(2);
+        assert!(!matrix.is_symmetric());
+        matrix.insert('o', '0', 0);
+        assert_eq!(matrix.cost('o', '0'), 0);
+        assert_eq!(matrix.cost('0', 'o'), 2);
+        let result = levenshtein_distance_with_matrix("foo", "f00", &matrix);
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_with_matrix("f00", "foo", &matrix);
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn local_default_costs() {
+        for (source, target) in [
+            ("long", "A long sentence"),
+            ("A long sentence", "long"),
+            ("lnog", "A long sentence"),
+            ("", "rust"),
+            ("bug", ""),
+        ] {
+            let result = local_levenshtein_distance_weighted(source, target, Costs::default());
+            assert_eq!(result, crate::local_levenshtein_distance(source, target));
+        }
+    }
+
+    #[test]
+    fn local_expensive_deletions() {
+        let cheap = Costs::default();
+        let expensive = Costs {
+            insert: 1,
+            delete: 4,
+            substitute: 1,
+        };
+        // The extra t has to be deleted, as the target has no other characters to substitute
+        let result = local_levenshtein_distance_weighted("rustt", "rust", cheap);
+        assert_eq!(result, 1);
+        let result = local_levenshtein_distance_weighted("rustt", "rust", expensive);
+        assert_eq!(result, 4);
+        // Missing characters only need insertions, so aren't affected
+        let result = local_levenshtein_distance_weighted("rst", "A rust crate", expensive);
+        assert_eq!(result, 1);
+        let result = local_levenshtein_distance_weighted("bug", "", expensive);
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn character_classes() {
+        let digits = |c: char| {
+            if c.is_ascii_digit() {
+                0
+            } else {
+                u32::from(c) + 1
+            }
+        };
+        let result = levenshtein_distance_with_classes("v1", "v9", digits);
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_with_classes("v1", "v10", digits);
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_with_classes("v1", "w1", digits);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn whitespace_classes() {
+        let whitespace = |c: char| {
+            if c.is_whitespace() {
+                0
+            } else {
+                u32::from(c) + 1
+            }
+        };
+        let result = levenshtein_distance_with_classes("a b\tc", "a\u{a0}b c", whitespace);
+        assert_eq!(result, 0);
+        // Every character in its own class is the same as levenshtein_distance
+        let result = levenshtein_distance_with_classes("kitten", "sitting", u32::from);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn prefix_weighted_positions() {
+        // The same substitution costs more at the start than at the end
+        let start = levenshtein_distance_prefix_weighted("xbcd", "abcd", 0.5);
+        let end = levenshtein_distance_prefix_weighted("abcx", "abcd", 0.5);
+        assert_eq!(start, 1.0);
+        assert_eq!(end, 0.125);
+        assert!(start > end);
+        // Inserting at the start and at the end
+        let result = levenshtein_distance_prefix_weighted("bcd", "abcd", 0.5);
+        assert_eq!(result, 1.0);
+        let result = levenshtein_distance_prefix_weighted("abc", "abcd", 0.5);
+        assert_eq!(result, 0.125);
+        let result = levenshtein_distance_prefix_weighted("", "ab", 0.5);
+        assert_eq!(result, 1.5);
+    }
+
+    #[test]
+    fn prefix_weighted_no_decay() {
+        for (source, target) in [("kitten", "sitting"), ("", "rust"), ("bug", "")] {
+            let result = levenshtein_distance_prefix_weighted(source, target, 1.0);
+            assert_eq!(result, crate::levenshtein_distance(source, target) as f64);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn prefix_weighted_growing_decay() {
+        levenshtein_distance_prefix_weighted("kitten", "sitting", 1.5);
+    }
+}
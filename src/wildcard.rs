@@ -0,0 +1,167 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A single element of a wildcard pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+    /// A character which has to be matched literally
+    Literal(char),
+    /// `?`, which matches any single character
+    AnyChar,
+    /// `*`, which matches any run of characters, including none
+    AnyRun,
+}
+
+/// Returns the minimum number of single character insertions, deletions or substitutions
+/// required to convert the pattern to the target string, where `?` in the pattern matches any
+/// single character of the target and `*` matches any run of characters of the target.
+///
+/// This is like [levenshtein_distance](crate::levenshtein_distance) with glob style wildcards
+/// in the pattern, so fixed parts of the pattern are still matched fuzzily.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_wildcard;
+/// assert_eq!(0, levenshtein_distance_wildcard("h?llo", "hello"));
+/// assert_eq!(0, levenshtein_distance_wildcard("h*o", "hello"));
+/// assert_eq!(1, levenshtein_distance_wildcard("h*p", "hello")); // substitute p for o
+/// ```
+///
+/// A `?` substitutes for any target character at no cost, but it still has to be matched
+/// against exactly one character, so deleting it from the pattern costs 1 as for any other
+/// character. A `*` can stand in for any number of target characters, so any insertions next
+/// to it are free, and it can match nothing at all, so it never needs to be deleted. All other
+/// insertions, deletions and substitutions cost 1.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_wildcard;
+/// // ? has to be deleted to match the empty string, but * doesn't
+/// assert_eq!(1, levenshtein_distance_wildcard("?", ""));
+/// assert_eq!(0, levenshtein_distance_wildcard("*", ""));
+/// ```
+///
+/// To match a literal `?`, `*` or `\` in the pattern, escape it with a backslash, as in `\?`,
+/// `\*` or `\\`. A backslash before any other character, or at the end of the pattern, is
+/// matched literally.
+///
+/// ```
+/// use fuzzy_string_distance::levenshtein_distance_wildcard;
+/// assert_eq!(0, levenshtein_distance_wildcard(r"why\?", "why?"));
+/// assert_eq!(1, levenshtein_distance_wildcard(r"why\?", "whys"));
+/// ```
+///
+/// Note, this compares strings on a unicode scalar value basis, as per [str::chars]. While
+/// this comparison is less likely to cut a 'character' in two than a byte by byte basis, it
+/// still does not compare grapheme clusters.
+pub fn levenshtein_distance_wildcard(pattern: &str, target: &str) -> usize {
+    let pattern = tokenize(pattern);
+    let target = target.chars().collect::<Vec<char>>();
+
+    // We'll have a matrix A of `pattern` length + 1 rows and `target` length + 1 columns as in
+    // the Levenshtein distance, and only need to store two rows at a time. The first row is
+    // converting an empty pattern to each prefix of the target, which is all insertions.
+    let mut edit_distances = (0..=target.len()).collect::<Vec<usize>>();
+    let mut new_edit_distances = vec![0; target.len() + 1];
+
+    for token in &pattern {
+        match *token {
+            Token::AnyRun => {
+                // A run can match nothing, leaving the entry above unchanged, or extend over one
+                // more target character from the entry to the left at no cost
+                new_edit_distances[0] = edit_distances[0];
+                for j in 0..target.len() {
+                    new_edit_distances[j + 1] =
+                        core::cmp::min(edit_distances[j + 1], new_edit_distances[j]);
+                }
+            }
+            Token::Literal(_) | Token::AnyChar => {
+                // Converting this prefix of the pattern to an empty string needs this token to
+                // be deleted
+                new_edit_distances[0] = edit_distances[0] + 1;
+                for (j, target_char) in target.iter().enumerate() {
+                    let deletion = edit_distances[j + 1] + 1;
+                    let insertion = new_edit_distances[j] + 1;
+                    let substitution = match *token {
+                        Token::Literal(c) if c != *target_char => edit_distances[j] + 1,
+                        _ => edit_distances[j],
+                    };
+                    new_edit_distances[j + 1] =
+                        core::cmp::min(deletion, core::cmp::min(insertion, substitution));
+                }
+            }
+        }
+        core::mem::swap(&mut edit_distances, &mut new_edit_distances);
+    }
+
+    edit_distances[target.len()]
+}
+
+/// Splits the pattern into its tokens, resolving any escaped characters.
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '?' => Token::AnyChar,
+            '*' => Token::AnyRun,
+            '\\' => match chars.next_if(|&next| matches!(next, '?' | '*' | '\\')) {
+                Some(escaped) => Token::Literal(escaped),
+                None => Token::Literal('\\'),
+            },
+            c => Token::Literal(c),
+        });
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn any_char() {
+        let result = levenshtein_distance_wildcard("h?llo", "hello");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_wildcard("h??lo", "hello");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_wildcard("h?llo", "hllo");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_wildcard("?", "é");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn any_run() {
+        let result = levenshtein_distance_wildcard("*", "hello");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_wildcard("he*", "hello");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_wildcard("*llo", "hello");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_wildcard("h*x*o", "hello");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_wildcard("hello*", "hello");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn escapes() {
+        let result = levenshtein_distance_wildcard(r"\*", "*");
+        assert_eq!(result, 0);
+        let result = levenshtein_distance_wildcard(r"\*", "a");
+        assert_eq!(result, 1);
+        let result = levenshtein_distance_wildcard(r"a\\b", r"a\b");
+        assert_eq!(result, 0);
+        // Backslashes before anything else are literal
+        let result = levenshtein_distance_wildcard(r"\d\", r"\d\");
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn no_wildcards() {
+        let pairs = [("kitten", "sitting"), ("", "rust"), ("bug", "")];
+        for (source, target) in pairs {
+            let result = levenshtein_distance_wildcard(source, target);
+            assert_eq!(result, crate::levenshtein_distance(source, target));
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::levenshtein_distance_slice;
+
+/// Returns the minimum number of single word insertions, deletions or substitutions required to
+/// convert the source string to the target string.
+///
+/// This is like [token_levenshtein_distance](crate::token_levenshtein_distance), but splits the
+/// strings into words following the Unicode word boundary rules instead of splitting on
+/// whitespace, so punctuation attached to a word isn't treated as part of it.
+///
+/// ```
+/// use fuzzy_string_distance::{token_levenshtein_distance, word_levenshtein_distance};
+/// assert_eq!(0, word_levenshtein_distance("don't, stop", "don't stop!"));
+/// assert_eq!(1, word_levenshtein_distance("don't, stop", "don't go!"));
+/// // Splitting on whitespace gives the tokens "don't," and "stop", and "don't" and "stop!"
+/// assert_eq!(2, token_levenshtein_distance("don't, stop", "don't stop!", None));
+/// ```
+///
+/// Both strings are split into words with [unicode_words](UnicodeSegmentation::unicode_words)
+/// from [unicode-segmentation](https://docs.rs/unicode-segmentation), which keeps the words
+/// between word boundaries that contain at least one letter or number, and drops everything
+/// else. Whitespace, punctuation and other separators are ignored, so they can't affect the
+/// distance, but apostrophes inside words such as "don't" and decimal points inside numbers
+/// such as "3.14" are kept as part of the word. Words are compared for exact equality, so
+/// "Stop" and "stop" are different words.
+///
+/// This is only available with the `unicode-segmentation` feature enabled.
+///
+/// See also:
+/// - [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+/// - [Unicode text segmentation](https://www.unicode.org/reports/tr29/)
+pub fn word_levenshtein_distance(source: &str, target: &str) -> usize {
+    let source = source.unicode_words().collect::<Vec<&str>>();
+    let target = target.unicode_words().collect::<Vec<&str>>();
+    levenshtein_distance_slice(&source, &target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_word_different() {
+        let result = word_levenshtein_distance(
+            "The quick brown fox jumps over the lazy dog.",
+            "The quick brown cat jumps over the lazy dog.",
+        );
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn punctuation_ignored() {
+        let result = word_levenshtein_distance("Hello, world!", "hello world");
+        assert_eq!(result, 1);
+        let result = word_levenshtein_distance("don't... stop", "(don't) - stop");
+        assert_eq!(result, 0);
+        let result = word_levenshtein_distance("pi is 3.14", "pi is 3 14");
+        assert_eq!(result, 2);
+        let result = word_levenshtein_distance("?!", "");
+        assert_eq!(result, 0);
+    }
+}